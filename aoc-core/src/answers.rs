@@ -0,0 +1,165 @@
+//! Accepted puzzle answers, used to catch solvers that start giving different results.
+//!
+//! The registry is a TOML file with one table per day:
+//!
+//! ```toml
+//! [day5]
+//! part1 = "35"
+//! part2 = "sha256:25fc0e7096fc653718202dc30b0c580b8ab87eac11a700cba03a7c021bc35b0c"
+//! ```
+//!
+//! Answers prefixed with `sha256:` are stored as the hex digest of the answer,
+//! so the real values need not be committed in plain text.
+
+use crate::Part;
+use anyhow::{bail, Context, Result};
+use sha2::{Digest, Sha256};
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::path::Path;
+use std::str::FromStr;
+
+const SHA256_PREFIX: &str = "sha256:";
+
+/// One recorded answer.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Expected {
+    Plain(String),
+    /// Lowercase hex SHA-256 digest of the answer.
+    Sha256(String),
+}
+
+impl Expected {
+    /// Records `answer` by its digest rather than its value.
+    pub fn hashed(answer: &str) -> Self {
+        Expected::Sha256(sha256_hex(answer))
+    }
+
+    pub fn matches(&self, answer: &str) -> bool {
+        match self {
+            Expected::Plain(expected) => expected == answer,
+            Expected::Sha256(digest) => *digest == sha256_hex(answer),
+        }
+    }
+}
+
+impl FromStr for Expected {
+    type Err = anyhow::Error;
+
+    fn from_str(value: &str) -> Result<Self> {
+        let Some(digest) = value.strip_prefix(SHA256_PREFIX) else {
+            return Ok(Expected::Plain(value.to_string()));
+        };
+
+        if digest.len() != 64 || !digest.chars().all(|c| c.is_ascii_hexdigit()) {
+            bail!("{value:?} is not a sha256 digest");
+        }
+        Ok(Expected::Sha256(digest.to_ascii_lowercase()))
+    }
+}
+
+impl fmt::Display for Expected {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Expected::Plain(answer) => write!(f, "{answer}"),
+            Expected::Sha256(digest) => write!(f, "{SHA256_PREFIX}{digest}"),
+        }
+    }
+}
+
+/// Every recorded answer, keyed by day and part.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Answers {
+    expected: BTreeMap<(u8, Part), Expected>,
+}
+
+impl Answers {
+    pub fn load(path: &Path) -> Result<Self> {
+        let contents = fs::read_to_string(path)
+            .with_context(|| format!("failed to read answers from {}", path.display()))?;
+
+        contents
+            .parse()
+            .with_context(|| format!("invalid answers in {}", path.display()))
+    }
+
+    pub fn get(&self, day: u8, part: Part) -> Option<&Expected> {
+        self.expected.get(&(day, part))
+    }
+
+    pub fn insert(&mut self, day: u8, part: Part, expected: Expected) {
+        self.expected.insert((day, part), expected);
+    }
+}
+
+impl FromStr for Answers {
+    type Err = anyhow::Error;
+
+    fn from_str(contents: &str) -> Result<Self> {
+        let tables: BTreeMap<String, BTreeMap<String, String>> = toml::from_str(contents)?;
+
+        let mut answers = Answers::default();
+        for (day_key, parts) in tables {
+            let day = day_key
+                .strip_prefix("day")
+                .and_then(|day| day.parse::<u8>().ok())
+                .with_context(|| format!("expected a table named like `day5`, got `{day_key}`"))?;
+
+            for (part_key, value) in parts {
+                let part = part_key
+                    .strip_prefix("part")
+                    .with_context(|| format!("expected `part1` or `part2` in {day_key}"))?
+                    .parse()?;
+                let expected = value
+                    .parse()
+                    .with_context(|| format!("invalid answer for {day_key}.{part_key}"))?;
+
+                answers.insert(day, part, expected);
+            }
+        }
+
+        Ok(answers)
+    }
+}
+
+fn sha256_hex(answer: &str) -> String {
+    Sha256::digest(answer.as_bytes())
+        .iter()
+        .map(|byte| format!("{byte:02x}"))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_answers() -> Result<()> {
+        let answers: Answers = "[day5]\npart1 = \"35\"\npart2 = \"sha256:25fc0e7096fc653718202dc30b0c580b8ab87eac11a700cba03a7c021bc35b0c\"\n".parse()?;
+
+        assert_eq!(
+            Some(&Expected::Plain("35".into())),
+            answers.get(5, Part::One)
+        );
+        assert!(answers.get(5, Part::Two).unwrap().matches("46"));
+        assert!(!answers.get(5, Part::Two).unwrap().matches("47"));
+        assert_eq!(None, answers.get(6, Part::One));
+        Ok(())
+    }
+
+    #[test]
+    fn test_hashed() {
+        let expected = Expected::hashed("288");
+
+        assert!(expected.matches("288"));
+        assert_eq!(expected, expected.to_string().parse().unwrap());
+    }
+
+    #[test]
+    fn test_invalid_answers() {
+        assert!("[dayfive]\npart1 = \"1\"".parse::<Answers>().is_err());
+        assert!("[day5]\npart3 = \"1\"".parse::<Answers>().is_err());
+        assert!("[day5]\npart1 = \"sha256:abc\"".parse::<Answers>().is_err());
+    }
+}
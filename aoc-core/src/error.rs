@@ -0,0 +1,178 @@
+//! Parse errors that point at the offending spot in the puzzle input.
+
+use miette::{Diagnostic, SourceSpan};
+use nom::Offset;
+use nom_supreme::error::{BaseErrorKind, ErrorTree, StackContext};
+use thiserror::Error;
+
+/// A malformed puzzle input, rendered by miette as a labelled snippet of the input.
+#[derive(Clone, Debug, Error, Diagnostic)]
+#[error("{message} at line {line}, column {column}")]
+#[diagnostic(code(aoc::parse))]
+pub struct ParseError {
+    pub message: String,
+    /// 1-based line of the offending input.
+    pub line: usize,
+    /// 1-based column, counted in characters.
+    pub column: usize,
+    label: String,
+    #[source_code]
+    input: String,
+    #[label("{label}")]
+    span: SourceSpan,
+}
+
+impl ParseError {
+    /// An error about the token starting at `fragment`, which must be a slice of `input`.
+    pub fn at(
+        input: &str,
+        fragment: &str,
+        message: impl Into<String>,
+        label: impl Into<String>,
+    ) -> Self {
+        let len = fragment.find(char::is_whitespace).unwrap_or(fragment.len());
+
+        Self::new(
+            input,
+            input.offset(fragment),
+            len,
+            message.into(),
+            label.into(),
+        )
+    }
+
+    /// Converts a failed nom parse of `input` into the deepest failure it recorded.
+    ///
+    /// The innermost `.context(..)` names what was being parsed, the base error
+    /// says what was expected there.
+    pub fn from_nom(input: &str, error: nom::Err<ErrorTree<&str>>) -> Self {
+        let tree = match error {
+            nom::Err::Error(tree) | nom::Err::Failure(tree) => tree,
+            nom::Err::Incomplete(_) => {
+                return Self::at(input, &input[input.len()..], "invalid input", "ended early");
+            }
+        };
+        let (fragment, expected, context) = deepest(&tree, None);
+        let message = match context {
+            Some(context) => format!("invalid {context}"),
+            None => String::from("invalid input"),
+        };
+
+        Self::at(input, fragment, message, expected)
+    }
+
+    /// Re-anchors an error raised while parsing `part`, a slice of `input`, onto the whole of `input`.
+    pub fn within(self, input: &str, part: &str) -> Self {
+        let offset = input.offset(part) + self.span.offset();
+
+        Self::new(input, offset, self.span.len(), self.message, self.label)
+    }
+
+    /// Byte offset of the offending input.
+    pub fn offset(&self) -> usize {
+        self.span.offset()
+    }
+
+    fn new(input: &str, offset: usize, len: usize, message: String, label: String) -> Self {
+        let before = &input[..offset];
+        let line = before.matches('\n').count() + 1;
+        let line_start = before.rfind('\n').map_or(0, |newline| newline + 1);
+        let column = before[line_start..].chars().count() + 1;
+
+        ParseError {
+            message,
+            line,
+            column,
+            label,
+            input: input.to_string(),
+            span: (offset, len).into(),
+        }
+    }
+}
+
+/// Finds the failure that got furthest into the input, with the innermost context around it.
+fn deepest<'a>(
+    tree: &ErrorTree<&'a str>,
+    context: Option<&'static str>,
+) -> (&'a str, String, Option<&'static str>) {
+    match tree {
+        ErrorTree::Base { location, kind } => {
+            let expected = match kind {
+                // Bare nom error kinds read like "error in TakeWhileMN", which helps nobody.
+                BaseErrorKind::Kind(_) => String::from("unexpected input"),
+                // The parser's own error already says what was wrong.
+                BaseErrorKind::External(error) => error.to_string(),
+                kind => kind.to_string(),
+            };
+            (location, expected, context)
+        }
+        ErrorTree::Stack { base, contexts } => {
+            let innermost = contexts.iter().find_map(|(_, context)| match context {
+                StackContext::Context(context) => Some(*context),
+                StackContext::Kind(_) => None,
+            });
+            deepest(base, innermost.or(context))
+        }
+        ErrorTree::Alt(alternatives) => alternatives
+            .iter()
+            .map(|alternative| deepest(alternative, context))
+            .min_by_key(|(location, _, _)| location.len())
+            .expect("an Alt error has at least one alternative"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nom::character::complete;
+    use nom::{IResult, Parser};
+    use nom_supreme::{tag::complete::tag, ParserExt};
+
+    #[test]
+    fn test_line_and_column() {
+        let input = "Time: 7 15\nDistance: 9 4x";
+        let error = ParseError::at(input, &input[24..], "invalid distance", "not a number");
+
+        assert_eq!(2, error.line);
+        assert_eq!(14, error.column);
+        assert_eq!(24, error.offset());
+        assert_eq!("invalid distance at line 2, column 14", error.to_string());
+    }
+
+    #[test]
+    fn test_within() {
+        let input = "Card 1: 1 | 2\nCard x: 3 | 4";
+        let line = input.lines().nth(1).unwrap();
+        let error = ParseError::at(line, &line[5..], "invalid card id", "not a number");
+
+        let error = error.within(input, line);
+        assert_eq!((2, 6), (error.line, error.column));
+    }
+
+    #[test]
+    fn test_from_nom() {
+        let input = "seeds: 79 x";
+        let result: IResult<&str, u64, ErrorTree<&str>> = tag("seeds: ")
+            .precedes(complete::u64)
+            .precedes(complete::u64.preceded_by(tag(" ")).context("seed"))
+            .parse(input);
+
+        let error = ParseError::from_nom(input, result.unwrap_err());
+        assert_eq!("invalid seed at line 1, column 11", error.to_string());
+        assert_eq!("expected an ascii digit", error.label);
+    }
+
+    #[test]
+    fn test_from_nom_external() {
+        let input = "seeds: 300";
+        let result: IResult<&str, u8, ErrorTree<&str>> = complete::digit1
+            .map_res(str::parse::<u8>)
+            .preceded_by(tag("seeds: "))
+            .context("seed")
+            .parse(input);
+
+        let error = ParseError::from_nom(input, result.unwrap_err());
+        assert_eq!("invalid seed at line 1, column 8", error.to_string());
+        assert_eq!("number too large to fit in target type", error.label);
+    }
+}
@@ -0,0 +1,106 @@
+//! Worked examples and their expected answers, listed in each day's `examples.toml`:
+//!
+//! ```toml
+//! [[example]]
+//! input = "test.txt"
+//! part1 = "142"
+//!
+//! [[example]]
+//! input = "test2.txt"
+//! part2 = "281"
+//! ```
+//!
+//! `input` is relative to the `examples.toml` file. A part without an answer is
+//! not checked for that example, since the puzzle text often gives each part
+//! its own example.
+
+use crate::{Expected, Part};
+use anyhow::{Context, Result};
+use serde::Deserialize;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// One example input and the answers it should give.
+#[derive(Debug)]
+pub struct Example {
+    /// The input file, as written in `examples.toml`.
+    pub name: String,
+    pub input: String,
+    pub expected: Vec<(Part, Expected)>,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Fixtures {
+    example: Vec<Fixture>,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Fixture {
+    input: PathBuf,
+    part1: Option<String>,
+    part2: Option<String>,
+}
+
+/// Reads every example listed in the `examples.toml` at `path`, along with its input.
+pub fn load(path: &Path) -> Result<Vec<Example>> {
+    let contents = fs::read_to_string(path)
+        .with_context(|| format!("failed to read examples from {}", path.display()))?;
+    let fixtures: Fixtures = toml::from_str(&contents)
+        .with_context(|| format!("invalid examples in {}", path.display()))?;
+    let dir = path.parent().unwrap_or(Path::new(""));
+
+    fixtures
+        .example
+        .into_iter()
+        .map(|fixture| {
+            let input_path = dir.join(&fixture.input);
+            let input = fs::read_to_string(&input_path)
+                .with_context(|| format!("failed to read example {}", input_path.display()))?;
+
+            let mut expected = Vec::new();
+            for (part, answer) in [(Part::One, fixture.part1), (Part::Two, fixture.part2)] {
+                if let Some(answer) = answer {
+                    expected.push((part, answer.parse()?));
+                }
+            }
+
+            Ok(Example {
+                name: fixture.input.display().to_string(),
+                input,
+                expected,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+
+    #[test]
+    fn test_load() -> Result<()> {
+        let dir = env::temp_dir().join(format!("aoc-examples-{}", std::process::id()));
+        fs::create_dir_all(&dir)?;
+        fs::write(dir.join("test.txt"), "1abc2\n")?;
+        fs::write(
+            dir.join("examples.toml"),
+            "[[example]]\ninput = \"test.txt\"\npart2 = \"12\"\n",
+        )?;
+
+        let examples = load(&dir.join("examples.toml"));
+        fs::remove_dir_all(&dir)?;
+
+        let examples = examples?;
+        assert_eq!(1, examples.len());
+        assert_eq!("test.txt", examples[0].name);
+        assert_eq!("1abc2\n", examples[0].input);
+        assert_eq!(
+            vec![(Part::Two, Expected::Plain("12".into()))],
+            examples[0].expected
+        );
+        Ok(())
+    }
+}
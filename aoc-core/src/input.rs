@@ -0,0 +1,202 @@
+//! Where a day's puzzle input is read from.
+
+use anyhow::{Context, Result};
+use flate2::read::MultiGzDecoder;
+use std::ffi::OsString;
+use std::fmt;
+use std::fs::File;
+use std::io::{self, BufReader, Read};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+const GZIP_MAGIC: &[u8] = &[0x1f, 0x8b];
+const ZSTD_MAGIC: &[u8] = &[0x28, 0xb5, 0x2f, 0xfd];
+
+/// A file path, or `-` for stdin.
+///
+/// gzip and zstd data is recognised by its magic bytes and decompressed on the fly,
+/// so `input.txt.gz` and `cat input.txt.zst |` both work.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum InputSource {
+    Stdin,
+    File(PathBuf),
+}
+
+impl InputSource {
+    /// Input for `day`: the file named by [`InputSource::env_var`] when it is set,
+    /// otherwise `day<N>/input.txt` relative to the working directory.
+    pub fn for_day(day: u8) -> Self {
+        match std::env::var_os(Self::env_var(day)) {
+            Some(value) => Self::from(value),
+            None => InputSource::File(PathBuf::from(format!("day{day}/input.txt"))),
+        }
+    }
+
+    /// Environment variable overriding the input of `day`, e.g. `AOC_INPUT_DAY5`.
+    pub fn env_var(day: u8) -> String {
+        format!("AOC_INPUT_DAY{day}")
+    }
+
+    pub fn read_to_string(&self) -> Result<String> {
+        let bytes = self
+            .read_bytes()
+            .with_context(|| format!("failed to read puzzle input from {self}"))?;
+
+        String::from_utf8(bytes).with_context(|| format!("puzzle input in {self} is not UTF-8"))
+    }
+
+    /// Streams the input, decompressing it on the fly.
+    pub fn open(&self) -> Result<Box<dyn Read>> {
+        self.open_reader()
+            .with_context(|| format!("failed to read puzzle input from {self}"))
+    }
+
+    /// The path of an uncompressed file, which can be read in place, e.g. by
+    /// mapping it into memory.
+    pub fn plain_file(&self) -> Result<Option<&Path>> {
+        let InputSource::File(path) = self else {
+            return Ok(None);
+        };
+
+        let mut magic = Vec::new();
+        File::open(path)
+            .and_then(|file| file.take(ZSTD_MAGIC.len() as u64).read_to_end(&mut magic))
+            .with_context(|| format!("failed to read puzzle input from {self}"))?;
+
+        Ok((!is_compressed(&magic)).then_some(path.as_path()))
+    }
+
+    fn read_bytes(&self) -> io::Result<Vec<u8>> {
+        let mut bytes = Vec::new();
+        self.open_reader()?.read_to_end(&mut bytes)?;
+
+        Ok(bytes)
+    }
+
+    fn open_reader(&self) -> io::Result<Box<dyn Read>> {
+        let mut raw: Box<dyn Read> = match self {
+            InputSource::Stdin => Box::new(io::stdin()),
+            InputSource::File(path) => Box::new(File::open(path)?),
+        };
+
+        // Peek at the magic bytes, then put them back in front of the rest.
+        let mut magic = Vec::new();
+        raw.by_ref()
+            .take(ZSTD_MAGIC.len() as u64)
+            .read_to_end(&mut magic)?;
+        let stream = BufReader::new(io::Cursor::new(magic.clone()).chain(raw));
+
+        Ok(if magic.starts_with(GZIP_MAGIC) {
+            Box::new(MultiGzDecoder::new(stream))
+        } else if magic.starts_with(ZSTD_MAGIC) {
+            Box::new(zstd::stream::read::Decoder::with_buffer(stream)?)
+        } else {
+            Box::new(stream)
+        })
+    }
+}
+
+fn is_compressed(magic: &[u8]) -> bool {
+    magic.starts_with(GZIP_MAGIC) || magic.starts_with(ZSTD_MAGIC)
+}
+
+impl From<OsString> for InputSource {
+    fn from(value: OsString) -> Self {
+        if value == "-" {
+            InputSource::Stdin
+        } else {
+            InputSource::File(PathBuf::from(value))
+        }
+    }
+}
+
+impl FromStr for InputSource {
+    type Err = std::convert::Infallible;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Ok(Self::from(OsString::from(value)))
+    }
+}
+
+impl fmt::Display for InputSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputSource::Stdin => write!(f, "<stdin>"),
+            InputSource::File(path) => write!(f, "{}", path.display()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use flate2::write::GzEncoder;
+    use flate2::Compression;
+    use std::fs;
+    use std::io::Write;
+
+    const EXAMPLE: &str = "Time:      7  15   30\nDistance:  9  40  200\n";
+
+    fn temp_file(name: &str, contents: &[u8]) -> InputSource {
+        let path = std::env::temp_dir().join(format!("aoc-core-{}-{name}", std::process::id()));
+        fs::write(&path, contents).unwrap();
+        InputSource::File(path)
+    }
+
+    #[test]
+    fn test_parse_source() {
+        assert_eq!(InputSource::Stdin, "-".parse().unwrap());
+        assert_eq!(
+            InputSource::File(PathBuf::from("day5/input.txt")),
+            "day5/input.txt".parse().unwrap()
+        );
+    }
+
+    #[test]
+    fn test_plain_file() -> Result<()> {
+        let source = temp_file("plain.txt", EXAMPLE.as_bytes());
+
+        assert_eq!(EXAMPLE, source.read_to_string()?);
+        assert!(source.plain_file()?.is_some());
+        Ok(())
+    }
+
+    #[test]
+    fn test_gzip_file() -> Result<()> {
+        let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+        encoder.write_all(EXAMPLE.as_bytes())?;
+        let source = temp_file("input.txt.gz", &encoder.finish()?);
+
+        assert_eq!(EXAMPLE, source.read_to_string()?);
+        assert_eq!(None, source.plain_file()?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_zstd_file() -> Result<()> {
+        let compressed = zstd::encode_all(EXAMPLE.as_bytes(), 0)?;
+        let source = temp_file("input.txt.zst", &compressed);
+
+        assert_eq!(EXAMPLE, source.read_to_string()?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_missing_file_names_path() {
+        let source = InputSource::File(PathBuf::from("day99/input.txt"));
+        let error = source.read_to_string().unwrap_err();
+
+        assert!(format!("{error:#}").contains("day99/input.txt"));
+    }
+
+    #[test]
+    fn test_day_from_env() {
+        std::env::set_var(InputSource::env_var(25), "-");
+
+        assert_eq!(InputSource::Stdin, InputSource::for_day(25));
+        assert_eq!(
+            InputSource::File(PathBuf::from("day24/input.txt")),
+            InputSource::for_day(24)
+        );
+    }
+}
@@ -0,0 +1,159 @@
+//! Shared plumbing for the Advent of Code 2023 solvers.
+//!
+//! Every day crate exposes a unit struct implementing [`Solution`]. Tooling that
+//! needs to treat days uniformly (running, timing, verifying) works through the
+//! object-safe [`DynSolution`] view, which every [`Solution`] gets for free.
+
+use anyhow::{bail, Context, Result};
+use std::any::Any;
+use std::fmt;
+use std::str::FromStr;
+
+pub mod answers;
+pub mod error;
+pub mod examples;
+pub mod input;
+
+pub use answers::{Answers, Expected};
+pub use error::ParseError;
+pub use input::InputSource;
+
+/// Which half of a day's puzzle to solve.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Part::One => write!(f, "1"),
+            Part::Two => write!(f, "2"),
+        }
+    }
+}
+
+impl FromStr for Part {
+    type Err = anyhow::Error;
+
+    fn from_str(part: &str) -> Result<Self> {
+        match part {
+            "1" => Ok(Part::One),
+            "2" => Ok(Part::Two),
+            _ => bail!("part must be 1 or 2, got {part:?}"),
+        }
+    }
+}
+
+/// A single day's puzzle: one parser shared by both parts, and a solver per part.
+pub trait Solution {
+    /// Day of the advent calendar, `1..=25`.
+    const DAY: u8;
+
+    /// Parsed form of the puzzle input handed to both parts.
+    type Input;
+
+    fn parse(&self, input: &str) -> Result<Self::Input>;
+
+    fn part1(&self, input: &Self::Input) -> Result<String>;
+
+    fn part2(&self, input: &Self::Input) -> Result<String>;
+
+    fn solve(&self, input: &Self::Input, part: Part) -> Result<String> {
+        match part {
+            Part::One => self.part1(input),
+            Part::Two => self.part2(input),
+        }
+    }
+}
+
+/// Type-erased [`Solution`] so different days can sit in one collection.
+pub trait DynSolution: Send + Sync {
+    fn day(&self) -> u8;
+
+    fn parse_any(&self, input: &str) -> Result<Box<dyn Any>>;
+
+    fn solve_any(&self, input: &dyn Any, part: Part) -> Result<String>;
+
+    fn run(&self, input: &str, part: Part) -> Result<String> {
+        let parsed = self.parse_any(input)?;
+        self.solve_any(parsed.as_ref(), part)
+    }
+}
+
+impl<S> DynSolution for S
+where
+    S: Solution + Send + Sync,
+    S::Input: 'static,
+{
+    fn day(&self) -> u8 {
+        S::DAY
+    }
+
+    fn parse_any(&self, input: &str) -> Result<Box<dyn Any>> {
+        Ok(Box::new(self.parse(input)?))
+    }
+
+    fn solve_any(&self, input: &dyn Any, part: Part) -> Result<String> {
+        let input = input
+            .downcast_ref::<S::Input>()
+            .with_context(|| format!("input was not parsed by day {}", S::DAY))?;
+        self.solve(input, part)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Sum;
+
+    impl Solution for Sum {
+        const DAY: u8 = 1;
+        type Input = Vec<u32>;
+
+        fn parse(&self, input: &str) -> Result<Vec<u32>> {
+            Ok(input
+                .split(',')
+                .map(|num| num.parse())
+                .collect::<Result<_, _>>()?)
+        }
+
+        fn part1(&self, input: &Vec<u32>) -> Result<String> {
+            Ok(input.iter().sum::<u32>().to_string())
+        }
+
+        fn part2(&self, input: &Vec<u32>) -> Result<String> {
+            Ok(input.iter().product::<u32>().to_string())
+        }
+    }
+
+    #[test]
+    fn test_dyn_solution() -> Result<()> {
+        let solution: &dyn DynSolution = &Sum;
+
+        assert_eq!(1, solution.day());
+        assert_eq!("9", solution.run("2,3,4", Part::One)?);
+        assert_eq!("24", solution.run("2,3,4", Part::Two)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_part() {
+        assert_eq!(Part::One, "1".parse().unwrap());
+        assert_eq!(Part::Two, "2".parse().unwrap());
+        assert!("3".parse::<Part>().is_err());
+    }
+
+    #[test]
+    fn test_foreign_input() {
+        let foreign: Box<dyn Any> = Box::new(String::from("2,3,4"));
+
+        assert!(Sum.solve_any(foreign.as_ref(), Part::One).is_err());
+    }
+}
@@ -0,0 +1,55 @@
+//! Times parsing and each part separately, for every day's `test.txt` and `input.txt`.
+//!
+//! Benchmarks are named `dayN/<file>/<step>`, so one day or input can be picked
+//! out with a filter, e.g. `cargo bench -p aoc -- day4/input.txt`.
+
+use aoc::solutions::SOLUTIONS;
+use aoc_core::{DynSolution, Part};
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use std::fs;
+use std::path::Path;
+
+const INPUTS: [&str; 2] = ["test.txt", "input.txt"];
+
+fn bench_solutions(c: &mut Criterion) {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("..");
+
+    for &solution in SOLUTIONS {
+        let day = solution.day();
+        for file in INPUTS {
+            // Not every day has an example checked in.
+            let Ok(input) = fs::read_to_string(root.join(format!("day{day}")).join(file)) else {
+                continue;
+            };
+            bench_input(c, solution, &format!("day{day}/{file}"), &input);
+        }
+    }
+}
+
+fn bench_input(c: &mut Criterion, solution: &dyn DynSolution, name: &str, input: &str) {
+    let parsed = solution
+        .parse_any(input)
+        .unwrap_or_else(|error| panic!("{name} failed to parse: {error:?}"));
+
+    let mut group = c.benchmark_group(name);
+    // Some parts take seconds per run on real inputs; keep full runs bearable.
+    group.sample_size(10);
+
+    group.bench_function("parse", |b| {
+        b.iter(|| solution.parse_any(black_box(input)).unwrap())
+    });
+    for part in Part::ALL {
+        group.bench_function(format!("part{part}"), |b| {
+            b.iter(|| {
+                solution
+                    .solve_any(black_box(parsed.as_ref()), part)
+                    .unwrap()
+            })
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_solutions);
+criterion_main!(benches);
@@ -0,0 +1,39 @@
+//! `aoc calibrate`: day 1's calibration sum over documents of any size.
+
+use anyhow::Result;
+use aoc_core::InputSource;
+use clap::Args;
+use day1::{DigitSet, MissingDigits, Strategy};
+
+#[derive(Args)]
+pub struct CalibrateArgs {
+    /// Calibration document, or `-` for stdin. Plain files are memory-mapped,
+    /// anything else is streamed; defaults to day 1's usual input.
+    #[arg(short, long)]
+    input: Option<InputSource>,
+
+    /// How to read each line: `digits` (part 1), `words` (part 2) or `compound`.
+    #[arg(short, long, default_value = "words")]
+    strategy: Strategy,
+
+    /// Which characters are digits: `ascii` or any `unicode` decimal digit.
+    #[arg(long, default_value = "ascii")]
+    digits: DigitSet,
+
+    /// What to do with lines without digits: `fail`, `skip` or `zero`.
+    #[arg(long, default_value = "fail")]
+    missing: MissingDigits,
+}
+
+pub fn calibrate(args: CalibrateArgs) -> Result<()> {
+    let source = args.input.unwrap_or_else(|| InputSource::for_day(1));
+    let calibrator = args.strategy.with_digits(args.digits)?;
+
+    let sum = match source.plain_file()? {
+        Some(path) => day1::sum_file(path, &calibrator, args.missing)?,
+        None => day1::sum_reader(source.open()?, &calibrator, args.missing)?,
+    };
+
+    println!("{sum}");
+    Ok(())
+}
@@ -0,0 +1,56 @@
+//! `aoc cascade`: which day 4 scratchcards won which copies.
+
+use anyhow::{bail, Result};
+use aoc_core::InputSource;
+use clap::{Args, ValueEnum};
+use std::io::{self, BufWriter, Write};
+
+/// Most cards a tree is drawn for; it has a line for every one in hand.
+const MAX_TREE_CARDS: u64 = 10_000;
+
+#[derive(Args)]
+pub struct CascadeArgs {
+    /// Scratchcards; defaults to day 4's usual input.
+    #[arg(short, long)]
+    input: Option<InputSource>,
+
+    #[arg(long, value_enum, default_value_t)]
+    format: CascadeFormat,
+}
+
+#[derive(Clone, Copy, Default, ValueEnum)]
+enum CascadeFormat {
+    /// Every card in hand, indented under the card that won it; small inputs only.
+    #[default]
+    Tree,
+    /// One object per card: its matches, the cards it wins, and copy counts.
+    Json,
+    /// The same as `json`, one row per card.
+    Csv,
+}
+
+pub fn cascade(args: CascadeArgs) -> Result<()> {
+    let input = args
+        .input
+        .unwrap_or_else(|| InputSource::for_day(4))
+        .read_to_string()?;
+    let cards = day4::parse(&input)?;
+    let mut out = BufWriter::new(io::stdout().lock());
+
+    match args.format {
+        CascadeFormat::Tree => {
+            let total = day4::total_cards(&cards)?;
+            if total > MAX_TREE_CARDS {
+                bail!("{total} cards are too many to draw as a tree; try --format json or csv");
+            }
+            day4::write_tree(&cards, &mut out)?;
+        }
+        CascadeFormat::Json => {
+            day4::write_json(&cards, &mut out)?;
+            writeln!(out)?;
+        }
+        CascadeFormat::Csv => day4::write_csv(&cards, &mut out)?,
+    }
+    out.flush()?;
+    Ok(())
+}
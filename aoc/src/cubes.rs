@@ -0,0 +1,53 @@
+//! `aoc cubes`: day 2's answers for a bag of your choosing.
+
+use anyhow::Result;
+use aoc_core::InputSource;
+use clap::Args;
+use day2::Cubes;
+use std::path::PathBuf;
+
+#[derive(Args)]
+pub struct CubesArgs {
+    #[command(flatten)]
+    bag: BagArgs,
+
+    /// Games to check; defaults to day 2's usual input.
+    #[arg(short, long)]
+    input: Option<InputSource>,
+}
+
+/// The bag day 2's games are judged against.
+#[derive(Args)]
+pub struct BagArgs {
+    /// The bag, e.g. `12 red, 13 green, 14 blue`; defaults to part 1's.
+    #[arg(short, long)]
+    bag: Option<Cubes>,
+
+    /// TOML file giving the bag's count of each colour, e.g. `red = 12`.
+    #[arg(long, conflicts_with = "bag")]
+    bag_file: Option<PathBuf>,
+}
+
+impl BagArgs {
+    pub fn load(self) -> Result<Cubes> {
+        match (self.bag, &self.bag_file) {
+            (Some(bag), _) => Ok(bag),
+            (None, Some(path)) => Cubes::load(path),
+            (None, None) => Ok(Cubes::puzzle_bag()),
+        }
+    }
+}
+
+pub fn cubes(args: CubesArgs) -> Result<()> {
+    let bag = args.bag.load()?;
+    let input = args
+        .input
+        .unwrap_or_else(|| InputSource::for_day(2))
+        .read_to_string()?;
+    let games = day2::parse(&input)?;
+
+    println!("Bag: {bag}");
+    println!("Possible games: {}", day2::sum_valid_ids(&games, &bag));
+    println!("Power: {}", day2::sum_powers(&games, &bag)?);
+    Ok(())
+}
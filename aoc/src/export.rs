@@ -0,0 +1,60 @@
+//! `aoc export`: day 2's parsed games as JSON or CSV.
+
+use crate::cubes::BagArgs;
+use anyhow::{Context, Result};
+use aoc_core::InputSource;
+use clap::{Args, ValueEnum};
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::path::PathBuf;
+
+#[derive(Args)]
+pub struct ExportArgs {
+    #[arg(long, value_enum, default_value_t)]
+    format: ExportFormat,
+
+    #[command(flatten)]
+    bag: BagArgs,
+
+    /// Games to export; defaults to day 2's usual input.
+    #[arg(short, long)]
+    input: Option<InputSource>,
+
+    /// Where to write the dataset; defaults to stdout.
+    #[arg(short, long)]
+    output: Option<PathBuf>,
+}
+
+#[derive(Clone, Copy, Default, ValueEnum)]
+enum ExportFormat {
+    /// An array of games, each with its pulls, smallest bag, power and validity.
+    #[default]
+    Json,
+    /// One row per pull, with a column per colour and the game's totals repeated.
+    Csv,
+}
+
+pub fn export(args: ExportArgs) -> Result<()> {
+    let bag = args.bag.load()?;
+    let input = args
+        .input
+        .unwrap_or_else(|| InputSource::for_day(2))
+        .read_to_string()?;
+    let games = day2::parse(&input)?;
+
+    let mut out: BufWriter<Box<dyn Write>> = BufWriter::new(match &args.output {
+        Some(path) => Box::new(
+            File::create(path).with_context(|| format!("failed to create {}", path.display()))?,
+        ),
+        None => Box::new(io::stdout().lock()),
+    });
+    match args.format {
+        ExportFormat::Json => {
+            day2::write_json(&games, &bag, &mut out)?;
+            writeln!(out)?;
+        }
+        ExportFormat::Csv => day2::write_csv(&games, &bag, &mut out)?,
+    }
+    out.flush()?;
+    Ok(())
+}
@@ -0,0 +1,64 @@
+//! `aoc infer`: the bags most likely to have produced a day 2 game log.
+
+use anyhow::Result;
+use aoc_core::InputSource;
+use clap::Args;
+
+#[derive(Args)]
+pub struct InferArgs {
+    /// Game log; defaults to day 2's usual input.
+    #[arg(short, long)]
+    input: Option<InputSource>,
+
+    /// Most cubes of one colour a candidate bag may hold.
+    #[arg(long, default_value_t = 30)]
+    max_count: u32,
+
+    /// Share of the probability each colour's interval should cover.
+    #[arg(long, default_value_t = 0.95)]
+    confidence: f64,
+
+    /// How many of the most likely bags to list.
+    #[arg(long, default_value_t = 10)]
+    top: usize,
+}
+
+pub fn infer(args: InferArgs) -> Result<()> {
+    let input = args
+        .input
+        .unwrap_or_else(|| InputSource::for_day(2))
+        .read_to_string()?;
+    let games = day2::parse(&input)?;
+    let inference = day2::infer_bag(&games, args.max_count, args.confidence, args.top)?;
+
+    println!(
+        "{} candidate bags of up to {} cubes per colour, equally likely beforehand.",
+        inference.considered, args.max_count
+    );
+    println!("{:>16} {:>12}  bag", "log-likelihood", "probability");
+    for candidate in &inference.candidates {
+        println!(
+            "{:>16.3} {:>11.4}%  {}",
+            candidate.log_likelihood,
+            candidate.probability * 100.0,
+            candidate.bag
+        );
+    }
+
+    let best = &inference.most_likely().bag;
+    if best.iter().any(|(_, count)| count == args.max_count) {
+        println!(
+            "The most likely bag is at --max-count; a bigger bag may explain the draws better."
+        );
+    }
+
+    println!();
+    println!("{}% intervals:", args.confidence * 100.0);
+    for interval in &inference.intervals {
+        println!(
+            "  {}: {} ({}..={})",
+            interval.colour, interval.estimate, interval.low, interval.high
+        );
+    }
+    Ok(())
+}
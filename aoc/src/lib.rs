@@ -0,0 +1,3 @@
+//! The solver registry, shared by the `aoc` binary and its benchmarks.
+
+pub mod solutions;
@@ -0,0 +1,247 @@
+use anyhow::{bail, Context, Result};
+use aoc::solutions;
+use aoc_core::{Answers, DynSolution, InputSource, ParseError, Part};
+use clap::{Args, Parser, Subcommand};
+use std::path::PathBuf;
+use std::process::ExitCode;
+use std::time::Instant;
+use timing::{Answer, Solved};
+use trace::TraceFormat;
+use tracing::info_span;
+
+mod calibrate;
+mod cascade;
+mod cubes;
+mod export;
+mod infer;
+mod scratchcards;
+mod spans;
+mod timing;
+mod trace;
+
+/// Advent of Code 2023 solutions.
+#[derive(Parser)]
+#[command(version, about)]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+
+    /// Write the solvers' tracing spans, with timings, to this file.
+    #[arg(long, global = true)]
+    trace: Option<PathBuf>,
+
+    /// Format of the `--trace` file.
+    #[arg(long, global = true, value_enum, default_value_t, requires = "trace")]
+    trace_format: TraceFormat,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Solve a single day, or every day with `--all`.
+    Run(RunArgs),
+    /// Check every day's answers for its real input against the answers registry.
+    Verify(VerifyArgs),
+    /// Sum day 1 calibration values of a document of any size, in parallel.
+    Calibrate(calibrate::CalibrateArgs),
+    /// Show where each day 1 line's first and last digit were found.
+    Spans(spans::SpansArgs),
+    /// Solve day 2 with a bag of any colours, given inline or in a TOML file.
+    Cubes(cubes::CubesArgs),
+    /// Estimate the contents of day 2's bag from every draw in its game log.
+    Infer(infer::InferArgs),
+    /// Write day 2's parsed games, judged against a bag, as JSON or CSV.
+    Export(export::ExportArgs),
+    /// Explain which day 4 scratchcards won which copies, as a tree or a table.
+    Cascade(cascade::CascadeArgs),
+    /// Play day 4's scratchcards under other scoring and copy rules.
+    Scratchcards(scratchcards::ScratchcardsArgs),
+}
+
+#[derive(Args)]
+struct RunArgs {
+    /// Day to solve.
+    #[arg(short, long, required_unless_present = "all")]
+    day: Option<u8>,
+
+    /// Only solve this part (1 or 2); both parts run when omitted.
+    #[arg(short, long)]
+    part: Option<Part>,
+
+    /// Puzzle input file, or `-` for stdin; gzip and zstd files are decompressed.
+    /// Defaults to `$AOC_INPUT_DAY<N>`, then `day<N>/input.txt`.
+    #[arg(short, long, conflicts_with = "all")]
+    input: Option<InputSource>,
+
+    /// Solve every day that has a solution.
+    #[arg(long, conflicts_with = "day")]
+    all: bool,
+
+    /// Print a table of parse and solve times instead of bare answers.
+    #[arg(long)]
+    time: bool,
+}
+
+#[derive(Args)]
+struct VerifyArgs {
+    /// Only verify this day.
+    #[arg(short, long)]
+    day: Option<u8>,
+
+    /// Answers registry; see `aoc_core::answers` for the format.
+    #[arg(long, default_value = "answers.toml")]
+    answers: PathBuf,
+}
+
+fn main() -> ExitCode {
+    match execute(Cli::parse()) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(error) => {
+            report(&error);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn execute(cli: Cli) -> Result<()> {
+    // Dropped last, so a Chrome trace is flushed after everything has run.
+    let _trace = match &cli.trace {
+        Some(path) => trace::install(path, cli.trace_format)?,
+        None => None,
+    };
+
+    match cli.command {
+        Command::Run(args) => run(args),
+        Command::Verify(args) => verify(args),
+        Command::Calibrate(args) => calibrate::calibrate(args),
+        Command::Spans(args) => spans::spans(args),
+        Command::Cubes(args) => cubes::cubes(args),
+        Command::Infer(args) => infer::infer(args),
+        Command::Export(args) => export::export(args),
+        Command::Cascade(args) => cascade::cascade(args),
+        Command::Scratchcards(args) => scratchcards::scratchcards(args),
+    }
+}
+
+/// Parse errors are drawn by miette as a labelled snippet of the input.
+fn report(error: &anyhow::Error) {
+    match error.downcast_ref::<ParseError>() {
+        Some(parse_error) => {
+            eprintln!("Error: {error}");
+            eprintln!("{:?}", miette::Report::new(parse_error.clone()));
+        }
+        None => eprintln!("Error: {error:?}"),
+    }
+}
+
+fn run(args: RunArgs) -> Result<()> {
+    let parts = match args.part {
+        Some(part) => vec![part],
+        None => Part::ALL.to_vec(),
+    };
+
+    let mut runs = Vec::new();
+    if args.all {
+        for &solution in solutions::SOLUTIONS {
+            let input = InputSource::for_day(solution.day());
+            runs.push(solve(solution, &input, &parts)?);
+            if !args.time {
+                print_answers(runs.last().unwrap());
+            }
+        }
+    } else {
+        let day = args.day.expect("clap requires --day without --all");
+        let solution = find(day)?;
+        let input = args.input.unwrap_or_else(|| InputSource::for_day(day));
+
+        runs.push(solve(solution, &input, &parts)?);
+        if !args.time {
+            print_answers(&runs[0]);
+        }
+    }
+
+    if args.time {
+        print!("{}", timing::table(&runs));
+    }
+    Ok(())
+}
+
+fn verify(args: VerifyArgs) -> Result<()> {
+    let answers = Answers::load(&args.answers)?;
+    let selected = match args.day {
+        Some(day) => vec![find(day)?],
+        None => solutions::SOLUTIONS.to_vec(),
+    };
+
+    let mut failures = 0;
+    for solution in selected {
+        let day = solution.day();
+        let solved = match solve(solution, &InputSource::for_day(day), &Part::ALL) {
+            Ok(solved) => solved,
+            Err(error) => {
+                report(&error);
+                failures += 1;
+                continue;
+            }
+        };
+
+        for Answer { part, value, .. } in solved.answers {
+            match answers.get(day, part) {
+                Some(expected) if expected.matches(&value) => {
+                    println!("Day {day} part {part}: ok")
+                }
+                Some(expected) => {
+                    println!("Day {day} part {part}: MISMATCH, got {value}, expected {expected}");
+                    failures += 1;
+                }
+                None => println!("Day {day} part {part}: no recorded answer, got {value}"),
+            }
+        }
+    }
+
+    if failures > 0 {
+        bail!("{failures} day(s) or part(s) failed verification");
+    }
+    Ok(())
+}
+
+fn find(day: u8) -> Result<&'static dyn DynSolution> {
+    solutions::find(day).with_context(|| format!("day {day} has no solution yet"))
+}
+
+fn solve(solution: &dyn DynSolution, input: &InputSource, parts: &[Part]) -> Result<Solved> {
+    let day = solution.day();
+    let input = input.read_to_string()?;
+
+    let start = Instant::now();
+    let parsed = info_span!("parse", day)
+        .in_scope(|| solution.parse_any(&input))
+        .with_context(|| format!("day {day} failed to parse its input"))?;
+    let parse = start.elapsed();
+
+    let answers = parts
+        .iter()
+        .map(|&part| {
+            let start = Instant::now();
+            let value = info_span!("solve", day, %part)
+                .in_scope(|| solution.solve_any(parsed.as_ref(), part))
+                .with_context(|| format!("day {day} part {part} failed"))?;
+            Ok(Answer {
+                part,
+                value,
+                elapsed: start.elapsed(),
+            })
+        })
+        .collect::<Result<_>>()?;
+
+    Ok(Solved {
+        day,
+        parse,
+        answers,
+    })
+}
+
+fn print_answers(solved: &Solved) {
+    for answer in &solved.answers {
+        println!("Day {} part {}: {}", solved.day, answer.part, answer.value);
+    }
+}
@@ -0,0 +1,52 @@
+//! `aoc scratchcards`: day 4's cards played under rules of your choosing.
+
+use anyhow::Result;
+use aoc_core::InputSource;
+use clap::Args;
+use day4::{CopyRule, Reach, Ruleset, Scoring};
+
+#[derive(Args)]
+pub struct ScratchcardsArgs {
+    /// Points per card: `doubling`, `linear:<points>` per match, or
+    /// `table:<points>,...` indexed by the number of matches.
+    #[arg(long, default_value = "doubling")]
+    scoring: Scoring,
+
+    /// Win copies of this many following cards: `matches`, one per match, or a
+    /// fixed number. Without it each card is played once.
+    #[arg(long)]
+    reach: Option<Reach>,
+
+    /// Carry winnings past the last card on from the first.
+    #[arg(long, requires = "reach")]
+    wrap: bool,
+
+    /// Most copies of one card that can be held, the original included.
+    #[arg(long, requires = "reach", value_parser = clap::value_parser!(u64).range(1..))]
+    cap: Option<u64>,
+
+    /// Scratchcards; defaults to day 4's usual input.
+    #[arg(short, long)]
+    input: Option<InputSource>,
+}
+
+pub fn scratchcards(args: ScratchcardsArgs) -> Result<()> {
+    let input = args
+        .input
+        .unwrap_or_else(|| InputSource::for_day(4))
+        .read_to_string()?;
+    let cards = day4::parse(&input)?;
+    let rules = Ruleset {
+        scoring: args.scoring,
+        copies: args.reach.map(|reach| CopyRule {
+            reach,
+            wrap: args.wrap,
+            cap: args.cap,
+        }),
+    };
+    let outcome = rules.play(&cards)?;
+
+    println!("Cards: {}", outcome.cards);
+    println!("Points: {}", outcome.points);
+    Ok(())
+}
@@ -0,0 +1,74 @@
+use aoc_core::DynSolution;
+use day1::Day1;
+use day2::Day2;
+use day4::Day4;
+use day5::Day5;
+use day6::Day6;
+use day7::Day7;
+
+/// Every solved day, in calendar order.
+pub static SOLUTIONS: &[&dyn DynSolution] = &[&Day1, &Day2, &Day4, &Day5, &Day6, &Day7];
+
+pub fn find(day: u8) -> Option<&'static dyn DynSolution> {
+    SOLUTIONS
+        .iter()
+        .copied()
+        .find(|solution| solution.day() == day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::examples;
+    use std::path::Path;
+
+    #[test]
+    fn test_calendar_order() {
+        let days: Vec<u8> = SOLUTIONS.iter().map(|solution| solution.day()).collect();
+
+        assert!(days.windows(2).all(|pair| pair[0] < pair[1]));
+    }
+
+    #[test]
+    fn test_find() {
+        assert_eq!(Some(5), find(5).map(|solution| solution.day()));
+        assert!(find(3).is_none());
+    }
+
+    /// Runs every example listed in a `dayN/examples.toml`.
+    #[test]
+    fn test_examples() {
+        let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("..");
+        let mut failures = Vec::new();
+
+        for &solution in SOLUTIONS {
+            let day = solution.day();
+            let path = root.join(format!("day{day}")).join("examples.toml");
+            let examples = examples::load(&path).unwrap();
+            assert!(!examples.is_empty(), "{} lists no examples", path.display());
+
+            for example in examples {
+                let name = format!("day {day} {}", example.name);
+                let parsed = match solution.parse_any(&example.input) {
+                    Ok(parsed) => parsed,
+                    Err(error) => {
+                        failures.push(format!("{name}: {error:#}"));
+                        continue;
+                    }
+                };
+
+                for (part, expected) in example.expected {
+                    match solution.solve_any(parsed.as_ref(), part) {
+                        Ok(answer) if expected.matches(&answer) => {}
+                        Ok(answer) => failures.push(format!(
+                            "{name} part {part}: got {answer}, expected {expected}"
+                        )),
+                        Err(error) => failures.push(format!("{name} part {part}: {error:#}")),
+                    }
+                }
+            }
+        }
+
+        assert!(failures.is_empty(), "{}", failures.join("\n"));
+    }
+}
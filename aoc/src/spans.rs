@@ -0,0 +1,89 @@
+//! `aoc spans`: shows which tokens produced each day 1 calibration value.
+
+use anyhow::Result;
+use aoc_core::{InputSource, Part};
+use clap::{Args, ValueEnum};
+use day1::{DigitSet, Highlight, Strategy, Unreadable};
+use std::io::{self, IsTerminal, Write};
+
+#[derive(Args)]
+pub struct SpansArgs {
+    /// Which part's rules to read the digits by.
+    #[arg(short, long, default_value = "2")]
+    part: Part,
+
+    /// Read by `digits`, `words` or `compound` rules instead of a part's.
+    #[arg(short, long)]
+    strategy: Option<Strategy>,
+
+    /// Which characters are digits: `ascii` or any `unicode` decimal digit.
+    #[arg(long, default_value = "ascii")]
+    digits: DigitSet,
+
+    /// Calibration document; defaults to day 1's usual input.
+    #[arg(short, long)]
+    input: Option<InputSource>,
+
+    #[arg(long, value_enum, default_value_t)]
+    format: SpansFormat,
+}
+
+#[derive(Clone, Copy, Default, ValueEnum)]
+enum SpansFormat {
+    /// Each line with its first and last digit highlighted.
+    #[default]
+    Text,
+    /// One JSON object per line, with byte ranges and matched text.
+    Json,
+}
+
+pub fn spans(args: SpansArgs) -> Result<()> {
+    let input = args
+        .input
+        .unwrap_or_else(|| InputSource::for_day(1))
+        .read_to_string()?;
+    let lines = day1::parse(&input);
+    let strategy = args
+        .strategy
+        .unwrap_or_else(|| Strategy::for_part(args.part));
+    let spans = day1::spans(&lines, &strategy.with_digits(args.digits)?);
+
+    let stdout = io::stdout();
+    let style = if stdout.is_terminal() {
+        Highlight::Ansi
+    } else {
+        Highlight::Brackets
+    };
+    let mut out = stdout.lock();
+
+    for line in &spans {
+        match args.format {
+            SpansFormat::Json => {
+                serde_json::to_writer(&mut out, line)?;
+                writeln!(out)?;
+            }
+            SpansFormat::Text => {
+                let summary = match (&line.first, &line.last, line.value) {
+                    (Some(first), Some(last), Some(value)) => format!(
+                        "{value} from {:?} at {}..{} and {:?} at {}..{}",
+                        first.text,
+                        first.found.start,
+                        first.found.end,
+                        last.text,
+                        last.found.start,
+                        last.found.end
+                    ),
+                    _ => line.problem.unwrap_or(Unreadable::NoDigits).to_string(),
+                };
+                writeln!(
+                    out,
+                    "{:>5}: {}  => {summary}",
+                    line.line,
+                    line.highlight(style)
+                )?;
+            }
+        }
+    }
+
+    Ok(())
+}
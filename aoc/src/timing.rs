@@ -0,0 +1,112 @@
+//! Wall-clock timings of a run, printed as a table by `aoc run --time`.
+
+use aoc_core::Part;
+use std::fmt::Write;
+use std::time::Duration;
+
+/// One day's answers along with how long each step took.
+pub struct Solved {
+    pub day: u8,
+    /// Parsing is shared by both parts, so it is timed once per day.
+    pub parse: Duration,
+    pub answers: Vec<Answer>,
+}
+
+pub struct Answer {
+    pub part: Part,
+    pub value: String,
+    pub elapsed: Duration,
+}
+
+const HEADERS: [&str; 6] = ["Day", "Part", "Answer", "Parse", "Solve", "Total"];
+
+/// Lays out one row per part and a grand total.
+///
+/// A day's parse time is shown and counted on its first row only, so the
+/// totals add up to the time actually spent.
+pub fn table(runs: &[Solved]) -> String {
+    let mut rows: Vec<[String; 6]> = Vec::new();
+    let mut grand_total = Duration::ZERO;
+
+    for run in runs {
+        let mut parse = Some(run.parse);
+        for answer in &run.answers {
+            let total = parse.unwrap_or_default() + answer.elapsed;
+            grand_total += total;
+
+            rows.push([
+                run.day.to_string(),
+                answer.part.to_string(),
+                answer.value.clone(),
+                parse.take().map(format_duration).unwrap_or_default(),
+                format_duration(answer.elapsed),
+                format_duration(total),
+            ]);
+        }
+    }
+
+    let mut widths = HEADERS.map(str::len);
+    for row in &rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+
+    let mut table = String::new();
+    let mut push_row = |cells: [&str; 6]| {
+        let line: Vec<String> = cells
+            .iter()
+            .zip(widths)
+            .map(|(cell, width)| format!("{cell:<width$}"))
+            .collect();
+        writeln!(table, "{}", line.join("  ").trim_end()).unwrap();
+    };
+
+    let dashes = widths.map(|width| "-".repeat(width));
+    push_row(HEADERS);
+    push_row(dashes.each_ref().map(String::as_str));
+    for row in &rows {
+        push_row(row.each_ref().map(String::as_str));
+    }
+    push_row(["", "", "", "", "", &format_duration(grand_total)]);
+
+    table
+}
+
+fn format_duration(duration: Duration) -> String {
+    format!("{duration:.2?}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_table() {
+        let runs = [Solved {
+            day: 6,
+            parse: Duration::from_micros(5),
+            answers: vec![
+                Answer {
+                    part: Part::One,
+                    value: "288".into(),
+                    elapsed: Duration::from_micros(10),
+                },
+                Answer {
+                    part: Part::Two,
+                    value: "71503".into(),
+                    elapsed: Duration::from_millis(2),
+                },
+            ],
+        }];
+
+        let expected = "\
+Day  Part  Answer  Parse   Solve    Total
+---  ----  ------  ------  -------  -------
+6    1     288     5.00µs  10.00µs  15.00µs
+6    2     71503           2.00ms   2.00ms
+                                    2.02ms
+";
+        assert_eq!(expected, table(&runs));
+    }
+}
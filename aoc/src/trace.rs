@@ -0,0 +1,47 @@
+//! `--trace` output: every span the solvers enter, with its timings, written to a file.
+
+use anyhow::{Context, Result};
+use clap::ValueEnum;
+use std::fs::File;
+use std::path::Path;
+use std::sync::Mutex;
+use tracing_chrome::{ChromeLayerBuilder, FlushGuard};
+use tracing_subscriber::fmt::format::FmtSpan;
+use tracing_subscriber::prelude::*;
+
+#[derive(Clone, Copy, Debug, Default, ValueEnum)]
+pub enum TraceFormat {
+    /// One JSON object per line for each span as it closes, with busy and idle time.
+    #[default]
+    Json,
+    /// Chrome trace-event format, for chrome://tracing, Perfetto or speedscope.
+    Chrome,
+}
+
+/// Installs the global subscriber writing to `path`.
+///
+/// A Chrome trace is only finished once the returned guard is dropped, so hold
+/// on to it until the run is over.
+pub fn install(path: &Path, format: TraceFormat) -> Result<Option<FlushGuard>> {
+    let file = File::create(path)
+        .with_context(|| format!("failed to create trace file {}", path.display()))?;
+
+    match format {
+        TraceFormat::Json => {
+            tracing_subscriber::fmt()
+                .json()
+                .with_span_events(FmtSpan::CLOSE)
+                .with_writer(Mutex::new(file))
+                .init();
+            Ok(None)
+        }
+        TraceFormat::Chrome => {
+            let (layer, guard) = ChromeLayerBuilder::new()
+                .writer(file)
+                .include_args(true)
+                .build();
+            tracing_subscriber::registry().with(layer).init();
+            Ok(Some(guard))
+        }
+    }
+}
@@ -0,0 +1,218 @@
+//! Calibration values, and what to do about lines that have no digits in them.
+
+use crate::Calibrator;
+use anyhow::bail;
+use serde::Serialize;
+use std::fmt;
+use std::str::FromStr;
+use thiserror::Error;
+
+/// What to do with a line that has no digits.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum MissingDigits {
+    /// Reject the document, listing every such line.
+    #[default]
+    Fail,
+    /// Leave the line out of the sum.
+    Skip,
+    /// Count the line as a calibration value of 0.
+    Zero,
+}
+
+impl FromStr for MissingDigits {
+    type Err = anyhow::Error;
+
+    fn from_str(policy: &str) -> anyhow::Result<Self> {
+        match policy {
+            "fail" => Ok(MissingDigits::Fail),
+            "skip" => Ok(MissingDigits::Skip),
+            "zero" => Ok(MissingDigits::Zero),
+            _ => bail!("expected `fail`, `skip` or `zero`, got `{policy}`"),
+        }
+    }
+}
+
+/// Why a line has no calibration value.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Unreadable {
+    /// Nothing in it reads as a digit.
+    NoDigits,
+    /// Its numbers written end to end, or one run of digits, don't fit in a `u64`.
+    TooBig,
+}
+
+impl fmt::Display for Unreadable {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Unreadable::NoDigits => write!(f, "no digits"),
+            Unreadable::TooBig => write!(f, "too big for a u64"),
+        }
+    }
+}
+
+/// A line of the document with no digits in it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LineWithoutDigits {
+    /// 1-based line number.
+    pub number: usize,
+    pub text: String,
+}
+
+/// Every line without digits, reported together so they can all be fixed at once.
+#[derive(Clone, Debug, Error, PartialEq, Eq)]
+pub struct MissingDigitsError {
+    pub lines: Vec<LineWithoutDigits>,
+}
+
+impl fmt::Display for MissingDigitsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.lines.len() {
+            1 => write!(f, "1 line has no digits")?,
+            count => write!(f, "{count} lines have no digits")?,
+        }
+        for line in &self.lines {
+            write!(f, "\n  line {}: {:?}", line.number, line.text)?;
+        }
+        Ok(())
+    }
+}
+
+/// A line of the document whose value doesn't fit in a `u64`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LineTooBig {
+    /// 1-based line number.
+    pub number: usize,
+    pub text: String,
+}
+
+/// Every line whose value is too big. Unlike missing digits, these are never
+/// skipped: the line has a value, it just can't be counted.
+#[derive(Clone, Debug, Error, PartialEq, Eq)]
+pub struct ValueTooBigError {
+    pub lines: Vec<LineTooBig>,
+}
+
+impl fmt::Display for ValueTooBigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.lines.len() {
+            1 => write!(f, "1 line has a value too big for a u64")?,
+            count => write!(f, "{count} lines have values too big for a u64")?,
+        }
+        for line in &self.lines {
+            write!(f, "\n  line {}: {:?}", line.number, line.text)?;
+        }
+        Ok(())
+    }
+}
+
+/// Lists every line in which `calibrator` finds no digits.
+pub fn validate(lines: &[String], calibrator: &Calibrator) -> Vec<LineWithoutDigits> {
+    lines
+        .iter()
+        .enumerate()
+        .filter(|(_, line)| calibrator.read(line) == Err(Unreadable::NoDigits))
+        .map(|(index, line)| LineWithoutDigits {
+            number: index + 1,
+            text: line.clone(),
+        })
+        .collect()
+}
+
+/// The calibration value of each line, with lines lacking digits handled by
+/// `policy`. Fails with a [`MissingDigitsError`] or a [`ValueTooBigError`].
+pub fn calibration_values(
+    lines: &[String],
+    calibrator: &Calibrator,
+    policy: MissingDigits,
+) -> anyhow::Result<Vec<u64>> {
+    if policy == MissingDigits::Fail {
+        let missing = validate(lines, calibrator);
+        if !missing.is_empty() {
+            return Err(MissingDigitsError { lines: missing }.into());
+        }
+    }
+
+    let mut values = Vec::with_capacity(lines.len());
+    let mut too_big = Vec::new();
+    for (index, line) in lines.iter().enumerate() {
+        match calibrator.read(line) {
+            Ok(value) => values.push(value),
+            Err(Unreadable::TooBig) => too_big.push(LineTooBig {
+                number: index + 1,
+                text: line.clone(),
+            }),
+            Err(Unreadable::NoDigits) if policy == MissingDigits::Zero => values.push(0),
+            Err(Unreadable::NoDigits) => {}
+        }
+    }
+    if !too_big.is_empty() {
+        return Err(ValueTooBigError { lines: too_big }.into());
+    }
+
+    Ok(values)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Strategy;
+
+    fn lines(input: &str) -> Vec<String> {
+        input.lines().map(String::from).collect()
+    }
+
+    #[test]
+    fn test_validate() -> anyhow::Result<()> {
+        let calibrator = Strategy::Words.build()?;
+        let document = lines("1abc2\nnothing here\ntwo\n\n");
+
+        let missing = validate(&document, &calibrator);
+        assert_eq!(
+            vec![2, 4],
+            missing.iter().map(|line| line.number).collect::<Vec<_>>()
+        );
+        assert_eq!("nothing here", missing[0].text);
+        Ok(())
+    }
+
+    #[test]
+    fn test_policies() -> anyhow::Result<()> {
+        let calibrator = Strategy::Digits.build()?;
+        let document = lines("1abc2\nabc\ntreb7uchet");
+
+        let error = calibration_values(&document, &calibrator, MissingDigits::Fail).unwrap_err();
+        assert_eq!("1 line has no digits\n  line 2: \"abc\"", error.to_string());
+        assert_eq!(
+            vec![12, 77],
+            calibration_values(&document, &calibrator, MissingDigits::Skip)?
+        );
+        assert_eq!(
+            vec![12, 0, 77],
+            calibration_values(&document, &calibrator, "zero".parse()?)?
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_too_big() -> anyhow::Result<()> {
+        let calibrator = Strategy::Compound.build()?;
+        let document = lines("12\nabc\n99999999999999999999");
+
+        assert_eq!(
+            vec![2],
+            validate(&document, &calibrator)
+                .iter()
+                .map(|line| line.number)
+                .collect::<Vec<_>>()
+        );
+        for policy in [MissingDigits::Skip, MissingDigits::Zero] {
+            let error = calibration_values(&document, &calibrator, policy).unwrap_err();
+            assert_eq!(
+                "1 line has a value too big for a u64\n  line 3: \"99999999999999999999\"",
+                error.to_string()
+            );
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,78 @@
+//! Which characters count as literal digits.
+
+use anyhow::bail;
+use std::str::FromStr;
+
+/// The zero of every run of ten decimal digits (general category `Nd`) in
+/// Unicode 14.0. Each script's digits are consecutive, so zero plus `n` is `n`.
+const UNICODE_ZEROS: [u32; 66] = [
+    0x0030, 0x0660, 0x06F0, 0x07C0, 0x0966, 0x09E6, 0x0A66, 0x0AE6, 0x0B66, 0x0BE6, 0x0C66, 0x0CE6,
+    0x0D66, 0x0DE6, 0x0E50, 0x0ED0, 0x0F20, 0x1040, 0x1090, 0x17E0, 0x1810, 0x1946, 0x19D0, 0x1A80,
+    0x1A90, 0x1B50, 0x1BB0, 0x1C40, 0x1C50, 0xA620, 0xA8D0, 0xA900, 0xA9D0, 0xA9F0, 0xAA50, 0xABF0,
+    0xFF10, 0x104A0, 0x10D30, 0x11066, 0x110F0, 0x11136, 0x111D0, 0x112F0, 0x11450, 0x114D0,
+    0x11650, 0x116C0, 0x11730, 0x118E0, 0x11950, 0x11C50, 0x11D50, 0x11DA0, 0x16A60, 0x16AC0,
+    0x16B50, 0x1D7CE, 0x1D7D8, 0x1D7E2, 0x1D7EC, 0x1D7F6, 0x1E140, 0x1E2F0, 0x1E950, 0x1FBF0,
+];
+
+/// The characters read as literal digits, by both parts alike.
+///
+/// Neither accepts characters that are numeric without being a decimal digit,
+/// such as `½`, `²` or `Ⅻ`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum DigitSet {
+    /// Only `0` to `9`.
+    #[default]
+    Ascii,
+    /// Any Unicode decimal digit, such as `٣` or `३`, read as its value.
+    Unicode,
+}
+
+impl DigitSet {
+    /// Every digit in the set with its value.
+    pub fn digits(self) -> impl Iterator<Item = (char, u8)> {
+        let zeros = match self {
+            DigitSet::Ascii => &UNICODE_ZEROS[..1],
+            DigitSet::Unicode => &UNICODE_ZEROS[..],
+        };
+
+        zeros.iter().flat_map(|zero| {
+            (0..10).map(move |value| {
+                let digit = char::from_u32(zero + u32::from(value))
+                    .expect("decimal digits are valid chars");
+                (digit, value)
+            })
+        })
+    }
+}
+
+impl FromStr for DigitSet {
+    type Err = anyhow::Error;
+
+    fn from_str(set: &str) -> anyhow::Result<Self> {
+        match set {
+            "ascii" => Ok(DigitSet::Ascii),
+            "unicode" => Ok(DigitSet::Unicode),
+            _ => bail!("expected `ascii` or `unicode`, got `{set}`"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_digit_sets() {
+        let ascii: Vec<(char, u8)> = DigitSet::Ascii.digits().collect();
+        assert_eq!(10, ascii.len());
+        assert_eq!(('7', 7), ascii[7]);
+
+        let unicode: Vec<(char, u8)> = DigitSet::Unicode.digits().collect();
+        assert_eq!(660, unicode.len());
+        assert!(unicode.contains(&('٣', 3)));
+        assert!(unicode.contains(&('९', 9)));
+        assert!(unicode.contains(&('７', 7)));
+        assert!(unicode.iter().all(|(digit, _)| digit.is_numeric()));
+        assert!(!unicode.iter().any(|&(digit, _)| "½²Ⅻ".contains(digit)));
+    }
+}
@@ -0,0 +1,107 @@
+//! Day 1: Trebuchet?!
+//!
+//! Each line of the calibration document hides a two-digit calibration value made
+//! of its first and last digit. Part 1 only counts literal digits, part 2 also
+//! counts spelled-out digits such as `two`.
+
+use anyhow::Result;
+use aoc_core::Solution;
+
+mod calibration;
+mod digits;
+mod scan;
+mod spans;
+mod strategy;
+mod vocabulary;
+
+pub use calibration::{
+    calibration_values, validate, LineTooBig, LineWithoutDigits, MissingDigits, MissingDigitsError,
+    Unreadable, ValueTooBigError,
+};
+pub use digits::DigitSet;
+pub use scan::{sum_bytes, sum_file, sum_reader};
+pub use spans::{spans, Highlight, LineSpans, Token};
+pub use strategy::{Calibrator, Strategy};
+pub use vocabulary::{DigitExtractor, DigitMatch, Vocabulary};
+
+/// Hooks day 1 into the [`Solution`] runner.
+pub struct Day1;
+
+impl Solution for Day1 {
+    const DAY: u8 = 1;
+    /// The document as is; the scanner reads it without splitting it into lines.
+    type Input = String;
+
+    fn parse(&self, input: &str) -> Result<String> {
+        Ok(input.to_string())
+    }
+
+    fn part1(&self, input: &String) -> Result<String> {
+        Ok(part1(input)?.to_string())
+    }
+
+    fn part2(&self, input: &String) -> Result<String> {
+        Ok(part2(input)?.to_string())
+    }
+}
+
+/// Splits the calibration document into its lines.
+pub fn parse(input: &str) -> Vec<String> {
+    input.lines().map(String::from).collect()
+}
+
+/// Sums the calibration values built from the literal digits of each line.
+pub fn sum_digit_values(lines: &[String]) -> Result<u64> {
+    sum_values(lines, &Strategy::Digits.build()?, MissingDigits::Fail)
+}
+
+/// Sums the calibration values built from literal and spelled-out digits.
+pub fn sum_spelled_values(lines: &[String]) -> Result<u64> {
+    sum_values(lines, &Strategy::Words.build()?, MissingDigits::Fail)
+}
+
+/// Sums the calibration values as `calibrator` reads them.
+pub fn sum_values(lines: &[String], calibrator: &Calibrator, policy: MissingDigits) -> Result<u64> {
+    let values = calibration_values(lines, calibrator, policy)?;
+
+    Ok(values.into_iter().sum())
+}
+
+/// Solves part 1 for a whole puzzle input.
+pub fn part1(input: &str) -> Result<u64> {
+    sum_bytes(
+        input.as_bytes(),
+        &Strategy::Digits.build()?,
+        MissingDigits::Fail,
+    )
+}
+
+/// Solves part 2 for a whole puzzle input.
+pub fn part2(input: &str) -> Result<u64> {
+    sum_bytes(
+        input.as_bytes(),
+        &Strategy::Words.build()?,
+        MissingDigits::Fail,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part1() -> Result<()> {
+        let input = "1abc2\npqr3stu8vwx\na1b2c3d4e5f\ntreb7uchet";
+
+        assert_eq!(142, part1(input)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_part2() -> Result<()> {
+        let input = "two1nine\neightwothree\nabcone2threexyz\nxtwone3four\n4nineeightseven2\nzoneight234\n7pqrstsixteen";
+
+        assert_eq!(281, part2(input)?);
+        Ok(())
+    }
+}
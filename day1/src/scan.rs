@@ -0,0 +1,335 @@
+//! Summing calibration documents far bigger than the puzzle input.
+//!
+//! The document is cut into line-aligned chunks that rayon scores in parallel,
+//! straight from the bytes: no line is copied or allocated, and non-UTF-8
+//! bytes are simply never digits.
+
+use crate::{
+    Calibrator, LineTooBig, LineWithoutDigits, MissingDigits, MissingDigitsError, Unreadable,
+    ValueTooBigError,
+};
+use anyhow::{bail, Context, Result};
+use memmap2::Mmap;
+use rayon::prelude::*;
+use std::fs::File;
+use std::io::Read;
+use std::path::Path;
+
+/// Smallest chunk worth handing to another thread.
+const MIN_CHUNK: usize = 1 << 20;
+/// How much of a stream is read before scoring it.
+const BLOCK: usize = 64 << 20;
+
+/// Sums the calibration values of a whole document in memory.
+pub fn sum_bytes(data: &[u8], calibrator: &Calibrator, policy: MissingDigits) -> Result<u64> {
+    tally(data, calibrator, policy).finish()
+}
+
+/// Sums the calibration values of a file by mapping it into memory.
+pub fn sum_file(path: &Path, calibrator: &Calibrator, policy: MissingDigits) -> Result<u64> {
+    let file = File::open(path).with_context(|| format!("failed to open {}", path.display()))?;
+    // SAFETY: the map is only read, and only while `file` is open. Another
+    // process truncating the file meanwhile would fault, as with any mmap.
+    let data = unsafe { Mmap::map(&file) }
+        .with_context(|| format!("failed to map {} into memory", path.display()))?;
+
+    sum_bytes(&data, calibrator, policy)
+}
+
+/// Sums the calibration values of a stream, holding about one block of it in memory.
+pub fn sum_reader(
+    reader: impl Read,
+    calibrator: &Calibrator,
+    policy: MissingDigits,
+) -> Result<u64> {
+    sum_blocks(reader, BLOCK, calibrator, policy)
+}
+
+/// Reads `block` bytes at a time, however few each `read` returns, and
+/// scores each block's complete lines before reading the next.
+fn sum_blocks(
+    mut reader: impl Read,
+    block: usize,
+    calibrator: &Calibrator,
+    policy: MissingDigits,
+) -> Result<u64> {
+    let mut total = Tally::default();
+    let mut buffer = Vec::with_capacity(block);
+
+    loop {
+        let read = reader
+            .by_ref()
+            .take(block as u64)
+            .read_to_end(&mut buffer)
+            .context("failed to read calibration document")?;
+
+        if read == 0 {
+            total.append(tally(&buffer, calibrator, policy));
+            return total.finish();
+        }
+
+        // Score the complete lines and carry the partial one into the next block.
+        if let Some(newline) = buffer.iter().rposition(|&byte| byte == b'\n') {
+            total.append(tally(&buffer[..=newline], calibrator, policy));
+            buffer.drain(..=newline);
+        }
+    }
+}
+
+/// Running totals over part of a document.
+#[derive(Default)]
+struct Tally {
+    sum: u64,
+    lines: usize,
+    missing: Vec<LineWithoutDigits>,
+    too_big: Vec<LineTooBig>,
+    overflowed: bool,
+}
+
+impl Tally {
+    /// Adds the totals of the lines following this tally's.
+    fn append(&mut self, next: Tally) {
+        let (sum, overflowed) = self.sum.overflowing_add(next.sum);
+        self.sum = sum;
+        self.overflowed |= overflowed || next.overflowed;
+        self.missing
+            .extend(next.missing.into_iter().map(|line| LineWithoutDigits {
+                number: line.number + self.lines,
+                ..line
+            }));
+        self.too_big
+            .extend(next.too_big.into_iter().map(|line| LineTooBig {
+                number: line.number + self.lines,
+                ..line
+            }));
+        self.lines += next.lines;
+    }
+
+    fn finish(self) -> Result<u64> {
+        if !self.missing.is_empty() {
+            return Err(MissingDigitsError {
+                lines: self.missing,
+            }
+            .into());
+        }
+        if !self.too_big.is_empty() {
+            return Err(ValueTooBigError {
+                lines: self.too_big,
+            }
+            .into());
+        }
+        if self.overflowed {
+            bail!("the calibration values add up to more than {}", u64::MAX);
+        }
+        Ok(self.sum)
+    }
+}
+
+/// Scores `data` in parallel chunks and adds them up in order.
+fn tally(data: &[u8], calibrator: &Calibrator, policy: MissingDigits) -> Tally {
+    let chunk_size = (data.len() / rayon::current_num_threads().max(1) / 4).max(MIN_CHUNK);
+
+    chunks(data, chunk_size)
+        .par_iter()
+        .map(|chunk| tally_chunk(chunk, calibrator, policy))
+        .collect::<Vec<_>>()
+        .into_iter()
+        .fold(Tally::default(), |mut total, next| {
+            total.append(next);
+            total
+        })
+}
+
+/// Splits `data` into pieces of about `size` bytes, each ending at a newline
+/// except possibly the last.
+fn chunks(data: &[u8], size: usize) -> Vec<&[u8]> {
+    let mut chunks = Vec::new();
+    let mut rest = data;
+
+    while rest.len() > size {
+        let end = match rest[size..].iter().position(|&byte| byte == b'\n') {
+            Some(newline) => size + newline + 1,
+            None => rest.len(),
+        };
+        let (chunk, tail) = rest.split_at(end);
+        chunks.push(chunk);
+        rest = tail;
+    }
+    if !rest.is_empty() {
+        chunks.push(rest);
+    }
+
+    chunks
+}
+
+fn tally_chunk(chunk: &[u8], calibrator: &Calibrator, policy: MissingDigits) -> Tally {
+    let mut tally = Tally::default();
+    let mut scratch = Vec::new();
+
+    if chunk.is_empty() {
+        return tally;
+    }
+
+    // Like `str::lines`: a final newline doesn't start another line, and `\r\n` ends one.
+    let chunk = chunk.strip_suffix(b"\n").unwrap_or(chunk);
+
+    for line in chunk.split(|&byte| byte == b'\n') {
+        let line = line.strip_suffix(b"\r").unwrap_or(line);
+        tally.lines += 1;
+
+        match calibrator.value_of(line, &mut scratch) {
+            Ok(value) => {
+                let (sum, overflowed) = tally.sum.overflowing_add(value);
+                tally.sum = sum;
+                tally.overflowed |= overflowed;
+            }
+            Err(Unreadable::TooBig) => tally.too_big.push(LineTooBig {
+                number: tally.lines,
+                text: String::from_utf8_lossy(line).into_owned(),
+            }),
+            Err(Unreadable::NoDigits) if policy == MissingDigits::Fail => {
+                tally.missing.push(LineWithoutDigits {
+                    number: tally.lines,
+                    text: String::from_utf8_lossy(line).into_owned(),
+                })
+            }
+            Err(Unreadable::NoDigits) => {}
+        }
+    }
+
+    tally
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{sum_values, Strategy};
+    use std::io;
+
+    const EXAMPLE: &str = "two1nine\neightwothree\nabcone2threexyz\nxtwone3four\n4nineeightseven2\nzoneight234\n7pqrstsixteen\n";
+
+    #[test]
+    fn test_chunks_end_at_newlines() {
+        let data = b"ab\ncd\nef\ng";
+
+        assert_eq!(vec![&b"ab\ncd\n"[..], b"ef\ng"], chunks(data, 4));
+        assert_eq!(vec![&data[..]], chunks(data, 100));
+        assert!(chunks(b"", 4).is_empty());
+    }
+
+    #[test]
+    fn test_matches_line_by_line() -> Result<()> {
+        let calibrator = Strategy::Words.build()?;
+        let document = EXAMPLE.repeat(1000);
+        let lines: Vec<String> = document.lines().map(String::from).collect();
+
+        let expected = sum_values(&lines, &calibrator, MissingDigits::Fail)?;
+        assert_eq!(281_000, expected);
+        assert_eq!(
+            expected,
+            sum_bytes(document.as_bytes(), &calibrator, MissingDigits::Fail)?
+        );
+        assert_eq!(
+            expected,
+            sum_reader(document.as_bytes(), &calibrator, MissingDigits::Fail)?
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_too_big_lines() -> Result<()> {
+        let calibrator = Strategy::Compound.build()?;
+        let data = b"12\nabc\n99999999999999999999\n34";
+
+        for policy in [MissingDigits::Skip, MissingDigits::Zero] {
+            let error = sum_bytes(data, &calibrator, policy).unwrap_err();
+            let error = error.downcast::<ValueTooBigError>()?;
+            assert_eq!(
+                vec![3],
+                error
+                    .lines
+                    .iter()
+                    .map(|line| line.number)
+                    .collect::<Vec<_>>()
+            );
+        }
+        let error = sum_bytes(data, &calibrator, MissingDigits::Fail).unwrap_err();
+        assert!(error.is::<MissingDigitsError>());
+        Ok(())
+    }
+
+    /// Hands out at most a few bytes per read, like a slow pipe.
+    struct Trickle<'a>(&'a [u8]);
+
+    impl Read for Trickle<'_> {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            let read = self.0.len().min(buf.len()).min(5);
+            buf[..read].copy_from_slice(&self.0[..read]);
+            self.0 = &self.0[read..];
+            Ok(read)
+        }
+    }
+
+    #[test]
+    fn test_short_reads() -> Result<()> {
+        let calibrator = Strategy::Words.build()?;
+        let document = EXAMPLE.repeat(10);
+
+        // Blocks of 16 bytes cut most lines in two, and each takes several reads.
+        assert_eq!(
+            2810,
+            sum_blocks(
+                Trickle(document.as_bytes()),
+                16,
+                &calibrator,
+                MissingDigits::Fail
+            )?
+        );
+        assert_eq!(
+            2810,
+            sum_reader(
+                Trickle(document.as_bytes()),
+                &calibrator,
+                MissingDigits::Fail
+            )?
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_missing_lines_are_numbered_across_chunks() -> Result<()> {
+        let calibrator = Strategy::Digits.build()?;
+        let data = b"1\r\nxx\n2\n\xff\n3";
+
+        let error = sum_bytes(data, &calibrator, MissingDigits::Fail).unwrap_err();
+        let error = error.downcast::<MissingDigitsError>()?;
+        assert_eq!(
+            vec![2, 4],
+            error
+                .lines
+                .iter()
+                .map(|line| line.number)
+                .collect::<Vec<_>>()
+        );
+        assert_eq!("\u{fffd}", error.lines[1].text);
+
+        let mut total = Tally::default();
+        for chunk in chunks(data, 2) {
+            total.append(tally_chunk(chunk, &calibrator, MissingDigits::Fail));
+        }
+        assert_eq!(
+            vec![2, 4],
+            total
+                .missing
+                .iter()
+                .map(|line| line.number)
+                .collect::<Vec<_>>()
+        );
+
+        assert_eq!(
+            11 + 22 + 33,
+            sum_bytes(data, &calibrator, MissingDigits::Skip)?
+        );
+        Ok(())
+    }
+}
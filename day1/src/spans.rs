@@ -0,0 +1,186 @@
+//! Where each line's first and last digit came from, for auditing calibration values.
+
+use crate::{Calibrator, DigitMatch, Unreadable};
+use serde::Serialize;
+
+/// A digit, or a whole number, as it was written in the line.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct Token {
+    #[serde(flatten)]
+    pub found: DigitMatch,
+    pub text: String,
+}
+
+/// The tokens that make up one line's calibration value.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct LineSpans {
+    /// 1-based line number.
+    pub line: usize,
+    pub text: String,
+    pub first: Option<Token>,
+    pub last: Option<Token>,
+    pub value: Option<u64>,
+    /// Why there is no value, if there isn't one.
+    pub problem: Option<Unreadable>,
+}
+
+/// How [`LineSpans::highlight`] marks the first and last digit.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Highlight {
+    /// Green for the first digit, cyan for the last, bold magenta where they overlap.
+    Ansi,
+    /// `[first]` and `{last}`, for output that isn't a terminal.
+    Brackets,
+}
+
+/// Explains every line of the document as `calibrator` reads it.
+pub fn spans(lines: &[String], calibrator: &Calibrator) -> Vec<LineSpans> {
+    lines
+        .iter()
+        .enumerate()
+        .map(|(index, line)| {
+            let ends = calibrator.ends(line);
+            let value = calibrator.read(line);
+            let token = |found: DigitMatch| Token {
+                found,
+                text: line[found.start..found.end].to_string(),
+            };
+
+            LineSpans {
+                line: index + 1,
+                text: line.clone(),
+                first: ends.map(|(first, _)| token(first)),
+                last: ends.map(|(_, last)| token(last)),
+                value: value.ok(),
+                problem: value.err(),
+            }
+        })
+        .collect()
+}
+
+impl LineSpans {
+    /// The line with its first and last digit marked, e.g. `[7]pqrst{six}teen`.
+    pub fn highlight(&self, style: Highlight) -> String {
+        let (Some(first), Some(last)) = (&self.first, &self.last) else {
+            return self.text.clone();
+        };
+        let first = first.found.start..first.found.end;
+        let last = last.found.start..last.found.end;
+
+        match style {
+            Highlight::Brackets => {
+                let mut marks = vec![
+                    (first.start, "["),
+                    (first.end, "]"),
+                    (last.start, "{"),
+                    (last.end, "}"),
+                ];
+                if first == last {
+                    marks.truncate(2);
+                }
+                // Closing marks go before opening ones at the same spot.
+                marks.sort_by_key(|&(at, mark)| (at, !matches!(mark, "]" | "}")));
+
+                let mut highlighted = String::new();
+                let mut copied = 0;
+                for (at, mark) in marks {
+                    highlighted.push_str(&self.text[copied..at]);
+                    highlighted.push_str(mark);
+                    copied = at;
+                }
+                highlighted.push_str(&self.text[copied..]);
+                highlighted
+            }
+            Highlight::Ansi => {
+                let mut cuts = vec![0, first.start, first.end, last.start, last.end];
+                cuts.push(self.text.len());
+                cuts.sort_unstable();
+                cuts.dedup();
+
+                cuts.windows(2)
+                    .map(|cut| {
+                        let segment = &self.text[cut[0]..cut[1]];
+                        let colour = match (first.contains(&cut[0]), last.contains(&cut[0])) {
+                            (true, true) => "\x1b[1;35m",
+                            (true, false) => "\x1b[32m",
+                            (false, true) => "\x1b[36m",
+                            (false, false) => return segment.to_string(),
+                        };
+                        format!("{colour}{segment}\x1b[0m")
+                    })
+                    .collect()
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Strategy;
+
+    fn explain(line: &str) -> LineSpans {
+        let calibrator = Strategy::Words.build().unwrap();
+        spans(&[line.to_string()], &calibrator).remove(0)
+    }
+
+    #[test]
+    fn test_spans() {
+        let line = explain("7pqrstsixteen");
+
+        assert_eq!(Some(76), line.value);
+        assert_eq!("7", line.first.as_ref().unwrap().text);
+        assert!(!line.first.unwrap().found.spelled);
+
+        let last = line.last.unwrap();
+        assert_eq!(
+            ("six", 6..9),
+            (last.text.as_str(), last.found.start..last.found.end)
+        );
+        assert!(last.found.spelled);
+    }
+
+    #[test]
+    fn test_problems() -> anyhow::Result<()> {
+        let calibrator = Strategy::Compound.build()?;
+        let lines = ["abc".to_string(), "99999999999999999999".to_string()];
+        let spans = spans(&lines, &calibrator);
+
+        assert_eq!(Some(Unreadable::NoDigits), spans[0].problem);
+        assert_eq!(
+            (None, Some(Unreadable::TooBig)),
+            (spans[1].value, spans[1].problem)
+        );
+        assert_eq!(None, explain("a1b").problem);
+        Ok(())
+    }
+
+    #[test]
+    fn test_highlight() {
+        assert_eq!(
+            "[7]pqrst{six}teen",
+            explain("7pqrstsixteen").highlight(Highlight::Brackets)
+        );
+        assert_eq!(
+            "x[tw{o]ne}",
+            explain("xtwone").highlight(Highlight::Brackets)
+        );
+        assert_eq!("ab[3]c", explain("ab3c").highlight(Highlight::Brackets));
+        assert_eq!("abc", explain("abc").highlight(Highlight::Brackets));
+
+        assert_eq!(
+            "\x1b[32mtw\x1b[0m\x1b[1;35mo\x1b[0m\x1b[36mne\x1b[0m",
+            explain("twone").highlight(Highlight::Ansi)
+        );
+    }
+
+    #[test]
+    fn test_json() {
+        let json = serde_json::to_string(&explain("a1b").first).unwrap();
+
+        assert_eq!(
+            r#"{"start":1,"end":2,"value":1,"spelled":false,"text":"1"}"#,
+            json
+        );
+    }
+}
@@ -0,0 +1,281 @@
+//! The ways of reading a calibration value out of a line, all built on [`DigitExtractor`].
+
+use crate::{DigitExtractor, DigitMatch, DigitSet, Unreadable, Vocabulary};
+use anyhow::{bail, Result};
+use aoc_core::Part;
+use std::str::FromStr;
+
+/// Which tokens make up a calibration value, and how they combine.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Strategy {
+    /// Part 1: the first and last literal digit.
+    Digits,
+    /// Part 2: the first and last digit, literal or spelled `one` to `nine`.
+    #[default]
+    Words,
+    /// The first and last whole number written end to end, so `twentyone x 7x35`
+    /// reads 21 and 35 and gives 2135. Numbers are runs of literal digits or
+    /// English words up to `ninetynine`, which may be hyphenated.
+    Compound,
+}
+
+impl Strategy {
+    pub fn for_part(part: Part) -> Self {
+        match part {
+            Part::One => Strategy::Digits,
+            Part::Two => Strategy::Words,
+        }
+    }
+
+    /// The words this strategy reads.
+    pub fn vocabulary(self) -> Vocabulary {
+        match self {
+            Strategy::Digits => Vocabulary::new(),
+            Strategy::Words => Vocabulary::english(),
+            Strategy::Compound => Vocabulary::english_numbers(),
+        }
+    }
+
+    /// Reads lines by this strategy with its usual vocabulary and ASCII digits.
+    pub fn build(self) -> Result<Calibrator> {
+        self.with_digits(DigitSet::Ascii)
+    }
+
+    /// Reads lines by this strategy with its usual vocabulary and `digits`.
+    pub fn with_digits(self, digits: DigitSet) -> Result<Calibrator> {
+        Ok(self.with_extractor(self.vocabulary().digits(digits).build()?))
+    }
+
+    /// Reads lines by this strategy with the words of `extractor`, e.g. to
+    /// combine whole numbers in another language.
+    pub fn with_extractor(self, extractor: DigitExtractor) -> Calibrator {
+        Calibrator {
+            extractor,
+            compound: self == Strategy::Compound,
+        }
+    }
+}
+
+impl FromStr for Strategy {
+    type Err = anyhow::Error;
+
+    fn from_str(strategy: &str) -> Result<Self> {
+        match strategy {
+            "digits" => Ok(Strategy::Digits),
+            "words" => Ok(Strategy::Words),
+            "compound" => Ok(Strategy::Compound),
+            _ => bail!("expected `digits`, `words` or `compound`, got `{strategy}`"),
+        }
+    }
+}
+
+/// Turns a line into its calibration value.
+#[derive(Clone, Debug)]
+pub struct Calibrator {
+    extractor: DigitExtractor,
+    compound: bool,
+}
+
+impl Calibrator {
+    /// The tokens the value is made of, first then last; the same token twice
+    /// when the line has only one. `None` if there is no value to read.
+    pub fn ends(&self, line: &str) -> Option<(DigitMatch, DigitMatch)> {
+        self.ends_of(line.as_bytes(), &mut Vec::new()).ok()
+    }
+
+    /// The line's calibration value, or `None` if [`Calibrator::read`] finds none.
+    pub fn calibration_value(&self, line: &str) -> Option<u64> {
+        self.read(line).ok()
+    }
+
+    /// The line's calibration value, or why it has none.
+    pub fn read(&self, line: &str) -> Result<u64, Unreadable> {
+        self.value_of(line.as_bytes(), &mut Vec::new())
+    }
+
+    /// Every whole number in `line`. Overlapping words stay separate numbers,
+    /// so `eightwo` still ends in a 2, as it does reading digits. Fails if a
+    /// run of digits doesn't fit in a `u64`.
+    pub fn numbers(&self, line: &str) -> Result<Vec<DigitMatch>, Unreadable> {
+        let mut numbers = Vec::new();
+        self.extractor.matches_into(line.as_bytes(), &mut numbers);
+        join_numbers(line.as_bytes(), &mut numbers)?;
+        Ok(numbers)
+    }
+
+    /// [`Calibrator::read`] of raw bytes, with `scratch` reused between lines.
+    pub(crate) fn value_of(
+        &self,
+        line: &[u8],
+        scratch: &mut Vec<DigitMatch>,
+    ) -> Result<u64, Unreadable> {
+        let (first, last) = self.ends_of(line, scratch)?;
+        if !self.compound {
+            return Ok(first.value * 10 + last.value);
+        }
+
+        10u64
+            .checked_pow(decimal_digits(last.value))
+            .and_then(|shift| first.value.checked_mul(shift))
+            .and_then(|value| value.checked_add(last.value))
+            .ok_or(Unreadable::TooBig)
+    }
+
+    fn ends_of(
+        &self,
+        line: &[u8],
+        scratch: &mut Vec<DigitMatch>,
+    ) -> Result<(DigitMatch, DigitMatch), Unreadable> {
+        self.extractor.matches_into(line, scratch);
+        if self.compound {
+            join_numbers(line, scratch)?;
+        }
+
+        match (scratch.first(), scratch.last()) {
+            (Some(&first), Some(&last)) => Ok((first, last)),
+            _ => Err(Unreadable::NoDigits),
+        }
+    }
+}
+
+/// Merges the tokens that make up one number, in place.
+fn join_numbers(line: &[u8], tokens: &mut Vec<DigitMatch>) -> Result<(), Unreadable> {
+    let mut kept = 0;
+    for index in 0..tokens.len() {
+        let found = tokens[index];
+        if kept > 0 {
+            let number = &mut tokens[kept - 1];
+            if let Some(between) = line.get(number.end..found.start) {
+                if let Some(value) = joined(between, number, &found)? {
+                    number.value = value;
+                    number.end = found.end;
+                    continue;
+                }
+            }
+        }
+
+        tokens[kept] = found;
+        kept += 1;
+    }
+    tokens.truncate(kept);
+    Ok(())
+}
+
+/// The value of `number` followed by `next`, if they form one number: a
+/// longer run of digits, or a tens word followed by a unit.
+fn joined(
+    between: &[u8],
+    number: &DigitMatch,
+    next: &DigitMatch,
+) -> Result<Option<u64>, Unreadable> {
+    let literal = |token: &DigitMatch| !token.spelled && token.value < 10;
+
+    if !number.spelled && literal(next) && between.is_empty() {
+        return number
+            .value
+            .checked_mul(10)
+            .and_then(|value| value.checked_add(next.value))
+            .map(Some)
+            .ok_or(Unreadable::TooBig);
+    }
+
+    let tens =
+        number.spelled && (20..=90).contains(&number.value) && number.value.is_multiple_of(10);
+    let unit = next.spelled && (1..=9).contains(&next.value);
+    if tens && unit && matches!(between, b"" | b"-") {
+        return Ok(Some(number.value + next.value));
+    }
+
+    Ok(None)
+}
+
+fn decimal_digits(value: u64) -> u32 {
+    value.checked_ilog10().unwrap_or(0) + 1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn values(calibrator: &Calibrator, line: &str) -> Vec<u64> {
+        calibrator
+            .numbers(line)
+            .unwrap()
+            .into_iter()
+            .map(|number| number.value)
+            .collect()
+    }
+
+    #[test]
+    fn test_compound() -> Result<()> {
+        let calibrator = Strategy::Compound.build()?;
+
+        assert_eq!(vec![21, 7, 35], values(&calibrator, "twentyone x 7x35"));
+        assert_eq!(Some(2135), calibrator.calibration_value("twentyone x 7x35"));
+        assert_eq!(
+            vec![99, 12, 40],
+            values(&calibrator, "ninety-nine twelve forty")
+        );
+        assert_eq!(vec![8, 2], values(&calibrator, "eightwo"));
+        assert_eq!(Some(1717), calibrator.calibration_value("seventeen"));
+        assert_eq!(Some(1020), calibrator.calibration_value("a10b20"));
+        assert_eq!(None, calibrator.calibration_value("xyz"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_compound_overflow() -> Result<()> {
+        let calibrator = Strategy::Compound.build()?;
+
+        assert_eq!(
+            Err(Unreadable::TooBig),
+            calibrator.read("99999999999999999999")
+        );
+        assert_eq!(
+            Err(Unreadable::TooBig),
+            calibrator.numbers("99999999999999999999")
+        );
+        assert_eq!(
+            Err(Unreadable::TooBig),
+            calibrator.read("9999999999 9999999999")
+        );
+        assert_eq!(Err(Unreadable::NoDigits), calibrator.read("xyz"));
+        assert_eq!(Ok(999_999_999_999_999_999), calibrator.read("999999999"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_unicode_digits() -> Result<()> {
+        // Arabic-Indic 4 and 2, Devanagari 9, superscript 2, a half and a fullwidth 7.
+        let line = "½٤x²two٢ ९ ７";
+
+        assert_eq!(None, Strategy::Digits.build()?.calibration_value(line));
+        assert_eq!(Some(22), Strategy::Words.build()?.calibration_value(line));
+
+        let digits = Strategy::Digits.with_digits(DigitSet::Unicode)?;
+        assert_eq!(Some(47), digits.calibration_value(line));
+        let words = Strategy::Words.with_digits(DigitSet::Unicode)?;
+        assert_eq!(Some(47), words.calibration_value(line));
+        let (first, last) = words.ends(line).unwrap();
+        assert_eq!(("٤", false), (&line[first.start..first.end], first.spelled));
+        assert_eq!("７", &line[last.start..last.end]);
+
+        let compound = Strategy::Compound.with_digits(DigitSet::Unicode)?;
+        assert_eq!(vec![4, 2, 2, 9, 7], values(&compound, line));
+        assert_eq!(Some(4213), compound.calibration_value("٤٢x１３"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_parts_share_the_engine() -> Result<()> {
+        let line = "twentyone3";
+
+        assert_eq!(Some(33), Strategy::Digits.build()?.calibration_value(line));
+        assert_eq!(Some(13), Strategy::Words.build()?.calibration_value(line));
+        assert_eq!(
+            Some(213),
+            Strategy::Compound.build()?.calibration_value(line)
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,384 @@
+//! Number words that count as digits, and the automaton that finds them.
+
+use crate::DigitSet;
+use aho_corasick::{AhoCorasick, AhoCorasickBuilder, MatchKind};
+use anyhow::{bail, Result};
+use serde::Serialize;
+use std::collections::BTreeMap;
+
+const ENGLISH: [&str; 9] = [
+    "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+];
+const FRENCH: [&str; 9] = [
+    "un", "deux", "trois", "quatre", "cinq", "six", "sept", "huit", "neuf",
+];
+const GERMAN: [&str; 9] = [
+    "eins", "zwei", "drei", "vier", "fünf", "sechs", "sieben", "acht", "neun",
+];
+const SPANISH: [&str; 9] = [
+    "uno", "dos", "tres", "cuatro", "cinco", "seis", "siete", "ocho", "nueve",
+];
+const ENGLISH_TEENS: [&str; 10] = [
+    "ten",
+    "eleven",
+    "twelve",
+    "thirteen",
+    "fourteen",
+    "fifteen",
+    "sixteen",
+    "seventeen",
+    "eighteen",
+    "nineteen",
+];
+const ENGLISH_TENS: [&str; 8] = [
+    "twenty", "thirty", "forty", "fifty", "sixty", "seventy", "eighty", "ninety",
+];
+
+/// The words spelling out each digit, on top of the literal digits of a [`DigitSet`].
+///
+/// ```
+/// use day1::Vocabulary;
+///
+/// let extractor = Vocabulary::english()
+///     .word("zero", 0)
+///     .case_insensitive(true)
+///     .build()?;
+/// assert_eq!(Some(80), extractor.calibration_value("xEIGHTwo, zero"));
+/// # anyhow::Ok(())
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct Vocabulary {
+    words: Vec<(String, u8)>,
+    numbers: Vec<(String, u64)>,
+    digits: DigitSet,
+    case_insensitive: bool,
+}
+
+impl Vocabulary {
+    /// Only the literal digits.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// `one` to `nine`, as in the puzzle.
+    pub fn english() -> Self {
+        Self::new().words(ENGLISH)
+    }
+
+    /// `zero` to `nineteen` and the tens up to `ninety`, for reading whole numbers.
+    pub fn english_numbers() -> Self {
+        let teens = (10..).zip(ENGLISH_TEENS);
+        let tens = (2..).map(|ten| ten * 10).zip(ENGLISH_TENS);
+
+        teens.chain(tens).fold(
+            Self::english().word("zero", 0),
+            |vocabulary, (value, word)| vocabulary.number(word, value),
+        )
+    }
+
+    /// `un` to `neuf`.
+    pub fn french() -> Self {
+        Self::new().words(FRENCH)
+    }
+
+    /// `eins` to `neun`.
+    pub fn german() -> Self {
+        Self::new().words(GERMAN)
+    }
+
+    /// `uno` to `nueve`.
+    pub fn spanish() -> Self {
+        Self::new().words(SPANISH)
+    }
+
+    /// Adds `word` as another spelling of `digit`.
+    pub fn word(mut self, word: impl Into<String>, digit: u8) -> Self {
+        self.words.push((word.into(), digit));
+        self
+    }
+
+    /// Adds `word` as the whole number `value`, such as `twenty` for 20.
+    ///
+    /// Only a [`Strategy::Compound`](crate::Strategy::Compound) reading makes
+    /// sense of these; reading digits, they would stand for a single digit.
+    pub fn number(mut self, word: impl Into<String>, value: u64) -> Self {
+        self.numbers.push((word.into(), value));
+        self
+    }
+
+    /// Adds every word of `other`, e.g. to accept English and German in one document.
+    pub fn merge(mut self, other: &Vocabulary) -> Self {
+        self.words.extend(other.words.iter().cloned());
+        self.numbers.extend(other.numbers.iter().cloned());
+        self
+    }
+
+    /// Which characters are read as literal digits; ASCII only by default.
+    pub fn digits(mut self, digits: DigitSet) -> Self {
+        self.digits = digits;
+        self
+    }
+
+    /// Whether words match regardless of case, so `FÜNF` is read as `fünf`.
+    /// Words and text are compared lowercased, so `ß` still doesn't match `SS`.
+    pub fn case_insensitive(mut self, yes: bool) -> Self {
+        self.case_insensitive = yes;
+        self
+    }
+
+    fn words(self, words: [&str; 9]) -> Self {
+        (1..=9).zip(words).fold(self, |vocabulary, (digit, word)| {
+            vocabulary.word(word, digit)
+        })
+    }
+
+    /// Compiles the vocabulary, checking that every word names one value.
+    pub fn build(&self) -> Result<DigitExtractor> {
+        // Each pattern's value, and whether it is spelled out rather than a literal digit.
+        let mut values: BTreeMap<String, (u64, bool)> = self
+            .digits
+            .digits()
+            .map(|(digit, value)| (digit.to_string(), (u64::from(value), false)))
+            .collect();
+
+        if let Some((word, digit)) = self.words.iter().find(|(_, digit)| *digit > 9) {
+            bail!("`{word}` stands for {digit}, which is not a single digit");
+        }
+        let words = self
+            .words
+            .iter()
+            .map(|(word, digit)| (word, u64::from(*digit)));
+
+        for (word, value) in words.chain(self.numbers.iter().map(|(word, value)| (word, *value))) {
+            if word.is_empty() {
+                bail!("number words can't be empty");
+            }
+
+            let key = if self.case_insensitive {
+                word.to_lowercase()
+            } else {
+                word.clone()
+            };
+            match values.insert(key, (value, true)) {
+                Some((previous, _)) if previous != value => {
+                    bail!("`{word}` stands for both {previous} and {value}")
+                }
+                _ => {}
+            }
+        }
+
+        let (patterns, (values, spelled)): (Vec<String>, (Vec<u64>, Vec<bool>)) =
+            values.into_iter().unzip();
+        let automaton = AhoCorasickBuilder::new()
+            .match_kind(MatchKind::Standard)
+            .ascii_case_insensitive(self.case_insensitive)
+            .build(&patterns)?;
+
+        Ok(DigitExtractor {
+            automaton,
+            values,
+            spelled,
+            fold_case: self.case_insensitive,
+        })
+    }
+}
+
+/// Finds every digit or number word in a line, written out or not, in one pass.
+#[derive(Clone, Debug)]
+pub struct DigitExtractor {
+    automaton: AhoCorasick,
+    /// The value each pattern stands for, by pattern id.
+    values: Vec<u64>,
+    /// Whether each pattern is a word rather than a literal digit.
+    spelled: Vec<bool>,
+    /// Whether lines are lowercased before matching; the automaton already
+    /// ignores ASCII case, so only lines with other bytes need it.
+    fold_case: bool,
+}
+
+/// A digit or number found in a line, with the byte range it was written in.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+pub struct DigitMatch {
+    pub start: usize,
+    pub end: usize,
+    pub value: u64,
+    /// Written as a word such as `six`, not as `6`.
+    pub spelled: bool,
+}
+
+impl DigitExtractor {
+    /// Every digit in `line` in the order they start, including ones that
+    /// overlap, so `twone` gives 2 then 1.
+    pub fn matches(&self, line: &str) -> Vec<DigitMatch> {
+        let mut matches = Vec::new();
+        self.matches_into(line.as_bytes(), &mut matches);
+        matches
+    }
+
+    /// [`DigitExtractor::matches`] on raw bytes, reusing `matches` to save an
+    /// allocation per line.
+    pub(crate) fn matches_into(&self, line: &[u8], matches: &mut Vec<DigitMatch>) {
+        matches.clear();
+        if self.fold_case && !line.is_ascii() {
+            let folded = Folded::new(line);
+            matches.extend(self.find(&folded.text).map(|found| DigitMatch {
+                start: folded.starts[found.start],
+                end: folded.ends[found.end - 1],
+                ..found
+            }));
+        } else {
+            matches.extend(self.find(line));
+        }
+
+        // Overlapping matches come out by where they end; of two starting
+        // together, the longer word wins.
+        matches.sort_unstable_by_key(|found| (found.start, std::cmp::Reverse(found.end)));
+        matches.dedup_by_key(|found| found.start);
+    }
+
+    fn find<'a>(&'a self, line: &'a [u8]) -> impl Iterator<Item = DigitMatch> + 'a {
+        self.automaton
+            .find_overlapping_iter(line)
+            .map(|found| DigitMatch {
+                start: found.start(),
+                end: found.end(),
+                value: self.values[found.pattern().as_usize()],
+                spelled: self.spelled[found.pattern().as_usize()],
+            })
+    }
+
+    /// The first digit followed by the last, or `None` if `line` has no digits.
+    pub fn calibration_value(&self, line: &str) -> Option<u64> {
+        let matches = self.matches(line);
+        let first = matches.first()?;
+        let last = matches.last()?;
+
+        Some(first.value * 10 + last.value)
+    }
+}
+
+/// A line lowercased, with where each of its bytes came from in the original.
+struct Folded {
+    text: Vec<u8>,
+    /// For each byte of `text`, the start of the character it was lowercased from.
+    starts: Vec<usize>,
+    /// For each byte of `text`, the end of the character it was lowercased from.
+    ends: Vec<usize>,
+}
+
+impl Folded {
+    /// Lowercases every valid character of `line`, keeping other bytes as they are.
+    fn new(line: &[u8]) -> Self {
+        let mut folded = Folded {
+            text: Vec::with_capacity(line.len()),
+            starts: Vec::with_capacity(line.len()),
+            ends: Vec::with_capacity(line.len()),
+        };
+        let mut offset = 0;
+
+        for chunk in line.utf8_chunks() {
+            for character in chunk.valid().chars() {
+                let end = offset + character.len_utf8();
+                let mut buffer = [0; 4];
+                for lower in character.to_lowercase() {
+                    folded.push(lower.encode_utf8(&mut buffer).as_bytes(), offset, end);
+                }
+                offset = end;
+            }
+            for &byte in chunk.invalid() {
+                folded.push(&[byte], offset, offset + 1);
+                offset += 1;
+            }
+        }
+
+        folded
+    }
+
+    fn push(&mut self, bytes: &[u8], start: usize, end: usize) {
+        self.text.extend_from_slice(bytes);
+        self.starts.resize(self.text.len(), start);
+        self.ends.resize(self.text.len(), end);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn digits(extractor: &DigitExtractor, line: &str) -> Vec<u64> {
+        extractor
+            .matches(line)
+            .into_iter()
+            .map(|found| found.value)
+            .collect()
+    }
+
+    #[test]
+    fn test_overlaps() -> Result<()> {
+        let extractor = Vocabulary::english().build()?;
+
+        assert_eq!(vec![2, 1, 3, 4], digits(&extractor, "xtwone3four"));
+        assert_eq!(vec![8, 2, 3], digits(&extractor, "eightwothree"));
+        assert_eq!(Some(83), extractor.calibration_value("eightwothree"));
+        assert_eq!(Some(77), extractor.calibration_value("treb7uchet"));
+        assert_eq!(None, extractor.calibration_value("pqrstu"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_languages_and_aliases() -> Result<()> {
+        let extractor = Vocabulary::german()
+            .merge(&Vocabulary::french())
+            .word("null", 0)
+            .case_insensitive(true)
+            .build()?;
+
+        assert_eq!(vec![5, 0, 3], digits(&extractor, "fünfNULLtrois"));
+        assert_eq!(vec![2, 9], digits(&extractor, "ZWEI-Neuf"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_unicode_case() -> Result<()> {
+        let extractor = Vocabulary::german()
+            .merge(&Vocabulary::french())
+            .case_insensitive(true)
+            .build()?;
+
+        assert_eq!(Some(55), extractor.calibration_value("FÜNF"));
+        assert_eq!(Some(55), extractor.calibration_value("Fünf"));
+        assert_eq!(Some(51), extractor.calibration_value("ÉtéFÜNFxUN"));
+        // Offsets are into the line as written, though `İ` lowercases to three bytes.
+        let found = extractor.matches("İFÜNFÿun");
+        assert_eq!((2, 7), (found[0].start, found[0].end));
+        assert_eq!((9, 11, 1), (found[1].start, found[1].end, found[1].value));
+        let mut found = Vec::new();
+        extractor.matches_into(b"\xffF\xc3\x9cNF", &mut found);
+        assert_eq!((1, 6, 5), (found[0].start, found[0].end, found[0].value));
+
+        let strict = Vocabulary::german().build()?;
+        assert_eq!(None, strict.calibration_value("FÜNF"));
+        let extractor = Vocabulary::new()
+            .word("ÜN", 1)
+            .case_insensitive(true)
+            .build()?;
+        assert_eq!(vec![1, 1], digits(&extractor, "ünÜn"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_case_sensitive_by_default() -> Result<()> {
+        let extractor = Vocabulary::english().build()?;
+
+        assert_eq!(vec![1], digits(&extractor, "ONEone"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_invalid_vocabulary() {
+        assert!(Vocabulary::new().word("ten", 10).build().is_err());
+        assert!(Vocabulary::new().word("", 1).build().is_err());
+        assert!(Vocabulary::english().word("one", 2).build().is_err());
+        assert!(Vocabulary::english().word("one", 1).build().is_ok());
+    }
+}
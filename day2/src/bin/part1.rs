@@ -1,193 +0,0 @@
-use anyhow::Result;
-use std::fs;
-
-#[derive(Debug, Eq, PartialEq)]
-struct Game {
-    id: i32,
-    pulls: Vec<Pull>,
-}
-
-impl Game {
-    fn new(id: i32) -> Self {
-        Game {
-            id,
-            pulls: Vec::new(),
-        }
-    }
-
-    fn is_valid(&self) -> bool {
-        for pull in self.pulls.clone() {
-            if pull.red > 12 || pull.blue > 14 || pull.green > 13 {
-                return false;
-            }
-        }
-
-        true
-    }
-}
-
-#[derive(Copy, Clone, Debug, Eq, PartialEq)]
-struct Pull {
-    red: i32,
-    blue: i32,
-    green: i32,
-}
-
-impl Default for Pull {
-    fn default() -> Self {
-        Pull {
-            red: 0,
-            blue: 0,
-            green: 0,
-        }
-    }
-}
-
-impl Pull {
-    fn new(red: i32, blue: i32, green: i32) -> Self {
-        Pull { red, blue, green }
-    }
-
-    fn update_red(&mut self, red: i32) {
-        self.red = red;
-    }
-
-    fn update_blue(&mut self, blue: i32) {
-        self.blue = blue;
-    }
-
-    fn update_green(&mut self, green: i32) {
-        self.green = green;
-    }
-}
-
-fn get_game_id(game_str: String) -> i32 {
-    let split = game_str.split(" ");
-    let id = split
-        .last()
-        .expect("should have found an id")
-        .parse::<i32>()
-        .unwrap();
-
-    id
-}
-
-fn process_line(line: String) -> Game {
-    let mut processed_games = Vec::new();
-    let mut split_game = line.split(": ");
-    let game_id = get_game_id(
-        split_game
-            .next()
-            .expect("should have found a game")
-            .clone()
-            .to_string(),
-    );
-    let games_string = match split_game.last() {
-        Some(games) => games,
-        None => panic!("unable to find games"),
-    };
-    let pulls: Vec<&str> = games_string.split("; ").collect();
-    for pull in pulls {
-        let mut pull_object = Pull::default();
-        let cubes: Vec<&str> = pull.split(", ").collect();
-        cubes.iter().for_each(|cube| {
-            let mut info = cube.split(" ");
-            let number_string = info.next().expect("should have found a number");
-            let number = number_string.parse::<i32>().unwrap();
-            let color = info.last().expect("should have found a color");
-
-            match color {
-                "red" => pull_object.update_red(number),
-                "blue" => pull_object.update_blue(number),
-                "green" => pull_object.update_green(number),
-                _ => println!("no colors found"),
-            }
-        });
-        processed_games.push(pull_object);
-    }
-
-    Game {
-        id: game_id,
-        pulls: processed_games,
-    }
-}
-
-fn main() -> Result<()> {
-    let input = fs::read_to_string("input.txt").unwrap();
-
-    let lines: Vec<String> = input.lines().map(String::from).collect();
-    let games: Vec<Game> = lines.into_iter().map(|line| process_line(line)).collect();
-
-    let valid_game_ids: Vec<i32> = games
-        .into_iter()
-        .filter(|game| game.is_valid())
-        .map(|game| game.id)
-        .collect();
-    let result: i32 = valid_game_ids.iter().sum();
-
-    println!("{result}");
-
-    Ok(())
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use std::fs;
-
-    #[test]
-    fn test_process_games_object() {
-        let input = fs::read_to_string("test.txt").unwrap();
-
-        let mut lines = input.lines().map(String::from);
-        let first_game = lines.next().expect("should have found a game!");
-        let game = process_line(first_game);
-        let answer = Game {
-            id: 1,
-            pulls: vec![Pull::new(4, 3, 0), Pull::new(1, 6, 2), Pull::new(0, 0, 2)],
-        };
-        assert_eq!(game, answer);
-    }
-
-    #[test]
-    fn test_valid_game() {
-        let input = fs::read_to_string("test.txt").unwrap();
-
-        let mut lines = input.lines().map(String::from);
-        let first_game = lines.next().expect("should have found a game!");
-        let game = process_line(first_game);
-
-        let result = game.is_valid();
-        let answer = true;
-        assert_eq!(result, answer);
-    }
-
-    #[test]
-    fn test_invalid_game() {
-        let input = String::from(
-            "Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red",
-        );
-        let game = process_line(input);
-
-        let result = game.is_valid();
-        let answer = false;
-        assert_eq!(result, answer);
-    }
-
-    #[test]
-    fn test_entire_input() {
-        let input = fs::read_to_string("test.txt").unwrap();
-
-        let lines: Vec<String> = input.lines().map(String::from).collect();
-        let games: Vec<Game> = lines.into_iter().map(|line| process_line(line)).collect();
-
-        let valid_game_ids: Vec<i32> = games
-            .into_iter()
-            .filter(|game| game.is_valid())
-            .map(|game| game.id)
-            .collect();
-        let result: i32 = valid_game_ids.iter().sum();
-        let answer = 8;
-        assert_eq!(result, answer);
-    }
-}
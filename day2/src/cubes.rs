@@ -0,0 +1,250 @@
+//! Cubes counted by colour, for handfuls and whole bags alike.
+
+use anyhow::{Context, Result};
+use aoc_core::ParseError;
+use nom::character::complete::{self, alpha1, space0, space1};
+use nom::combinator::consumed;
+use nom::error::{ContextError, ErrorKind, FromExternalError};
+use nom::multi::separated_list1;
+use nom::{IResult, Parser};
+use nom_supreme::{error::ErrorTree, tag::complete::tag, ParserExt};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::path::Path;
+use std::str::FromStr;
+use thiserror::Error;
+
+/// How many cubes there are of each colour; colours not listed have none.
+///
+/// Parses from the puzzle's own notation, e.g. `12 red, 13 green, 14 blue`, and
+/// (de)serializes as a table such as `red = 12`.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Cubes {
+    counts: BTreeMap<String, u32>,
+}
+
+impl Cubes {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The bag part 1 asks about: 12 red, 13 green and 14 blue cubes.
+    pub fn puzzle_bag() -> Self {
+        Self::new()
+            .with("red", 12)
+            .with("green", 13)
+            .with("blue", 14)
+    }
+
+    /// Reads a bag from a TOML file mapping each colour to its count.
+    pub fn load(path: &Path) -> Result<Self> {
+        let contents = fs::read_to_string(path)
+            .with_context(|| format!("failed to read bag from {}", path.display()))?;
+
+        toml::from_str(&contents).with_context(|| format!("invalid bag in {}", path.display()))
+    }
+
+    /// Adds `count` cubes of `colour`.
+    ///
+    /// # Panics
+    ///
+    /// If that makes more cubes of `colour` than a `u32` can count.
+    pub fn with(mut self, colour: &str, count: u32) -> Self {
+        if let Err(error) = self.add(colour, count) {
+            panic!("{error}");
+        }
+        self
+    }
+
+    /// Adds `count` cubes of `colour`, leaving the count as it was if the sum overflows.
+    pub fn add(&mut self, colour: &str, count: u32) -> Result<(), TooManyCubes> {
+        let held = self.counts.entry(colour.to_string()).or_default();
+        *held = held.checked_add(count).ok_or_else(|| TooManyCubes {
+            colour: colour.to_string(),
+        })?;
+        Ok(())
+    }
+
+    /// How many cubes of `colour` there are, zero if the colour isn't listed.
+    pub fn count(&self, colour: &str) -> u32 {
+        self.counts.get(colour).copied().unwrap_or(0)
+    }
+
+    /// Every listed colour with its count, in alphabetical order.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, u32)> {
+        self.counts
+            .iter()
+            .map(|(colour, &count)| (colour.as_str(), count))
+    }
+
+    pub fn colours(&self) -> impl Iterator<Item = &str> {
+        self.counts.keys().map(String::as_str)
+    }
+
+    /// Whether these cubes could all have come out of `bag` at once.
+    pub fn fits_in(&self, bag: &Cubes) -> bool {
+        self.iter()
+            .all(|(colour, count)| count <= bag.count(colour))
+    }
+
+    /// Grows each colour to at least its count in `other`, so both fit in the result.
+    pub fn include(&mut self, other: &Cubes) {
+        for (colour, count) in other.iter() {
+            let held = self.counts.entry(colour.to_string()).or_default();
+            *held = (*held).max(count);
+        }
+    }
+
+    /// The counts of `colours` multiplied together, zero if any is missing.
+    /// `None` if the product doesn't fit in a `u64`.
+    pub fn power<'a>(&self, colours: impl IntoIterator<Item = &'a str>) -> Option<u64> {
+        let counts: Vec<u64> = colours
+            .into_iter()
+            .map(|colour| u64::from(self.count(colour)))
+            .collect();
+        // A missing colour makes it zero however big the others are.
+        if counts.contains(&0) {
+            return Some(0);
+        }
+        counts
+            .into_iter()
+            .try_fold(1u64, |power, count| power.checked_mul(count))
+    }
+}
+
+/// More cubes of one colour than a `u32` can count.
+#[derive(Clone, Debug, Error, PartialEq, Eq)]
+#[error("more than {} {colour} cubes", u32::MAX)]
+pub struct TooManyCubes {
+    pub colour: String,
+}
+
+/// `<count> <colour>` pairs separated by commas, e.g. `3 blue, 4 red`. A
+/// colour listed twice counts both.
+pub(crate) fn cube_list(input: &str) -> IResult<&str, Cubes, ErrorTree<&str>> {
+    let (rest, cubes) = separated_list1(separator(","), consumed(cube.cut())).parse(input)?;
+
+    let mut all = Cubes::new();
+    for (text, (count, colour)) in cubes {
+        all.add(colour, count).map_err(|error| {
+            let error = ErrorTree::from_external_error(text, ErrorKind::MapRes, error);
+            nom::Err::Failure(ErrorTree::add_context(text, "cube count", error))
+        })?;
+    }
+    Ok((rest, all))
+}
+
+fn cube(input: &str) -> IResult<&str, (u32, &str), ErrorTree<&str>> {
+    complete::u32
+        .context("cube count")
+        .and(alpha1.preceded_by(space1).context("colour"))
+        .parse(input)
+}
+
+/// `symbol` with any spaces or tabs around it.
+pub(crate) fn separator<'a>(
+    symbol: &'static str,
+) -> impl Parser<&'a str, &'a str, ErrorTree<&'a str>> {
+    tag(symbol).delimited_by(space0)
+}
+
+impl FromStr for Cubes {
+    type Err = anyhow::Error;
+
+    fn from_str(text: &str) -> Result<Self> {
+        let (_, cubes) = cube_list
+            .delimited_by(space0)
+            .all_consuming()
+            .parse(text)
+            .map_err(|error| ParseError::from_nom(text, error))?;
+        Ok(cubes)
+    }
+}
+
+impl fmt::Display for Cubes {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (index, (colour, count)) in self.iter().enumerate() {
+            if index > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{count} {colour}")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_and_display() -> Result<()> {
+        let cubes: Cubes = "3 blue, 4 red, 2 mauve".parse()?;
+
+        assert_eq!(
+            Cubes::new().with("red", 4).with("blue", 3).with("mauve", 2),
+            cubes
+        );
+        assert_eq!("3 blue, 2 mauve, 4 red", cubes.to_string());
+        assert_eq!(0, cubes.count("green"));
+        assert_eq!(cubes, " 3 blue ,4 red,\t2 mauve ".parse()?);
+        assert!("3 blue, x red".parse::<Cubes>().is_err());
+        assert!("3 blue 4 red".parse::<Cubes>().is_err());
+        assert_eq!(Cubes::new().with("red", 5), "2 red, 3 red".parse()?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_fits_and_power() {
+        let bag = Cubes::puzzle_bag();
+        let pull = Cubes::new().with("red", 12).with("blue", 1);
+
+        assert!(pull.fits_in(&bag));
+        assert!(!pull.clone().with("red", 1).fits_in(&bag));
+        assert!(!Cubes::new().with("mauve", 1).fits_in(&bag));
+
+        let mut smallest = pull;
+        smallest.include(&Cubes::new().with("blue", 5).with("green", 2));
+        assert_eq!(
+            Cubes::new()
+                .with("red", 12)
+                .with("blue", 5)
+                .with("green", 2),
+            smallest
+        );
+        assert_eq!(Some(120), smallest.power(bag.colours()));
+        assert_eq!(Some(0), smallest.power(["red", "mauve"]));
+
+        let huge = ["a", "b", "c"]
+            .into_iter()
+            .fold(Cubes::new(), |cubes, colour| cubes.with(colour, u32::MAX));
+        assert_eq!(None, huge.power(huge.colours()));
+        assert_eq!(Some(0), huge.power(["a", "b", "c", "d"]));
+    }
+
+    #[test]
+    fn test_overflow() {
+        let mut cubes = Cubes::new().with("red", u32::MAX);
+
+        assert_eq!(
+            Err(TooManyCubes {
+                colour: String::from("red")
+            }),
+            cubes.add("red", 1)
+        );
+        assert_eq!(u32::MAX, cubes.count("red"));
+        assert!(cubes.add("blue", u32::MAX).is_ok());
+    }
+
+    #[test]
+    fn test_toml() -> Result<()> {
+        let bag: Cubes = toml::from_str("red = 12\ngreen = 13\nblue = 14\n")?;
+
+        assert_eq!(Cubes::puzzle_bag(), bag);
+        assert!(toml::from_str::<Cubes>("red = -1").is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,175 @@
+//! Parsed games as JSON or CSV, for spreadsheets and notebooks.
+
+use crate::{Cubes, Game, Pull};
+use serde::Serialize;
+use std::collections::BTreeSet;
+use std::io::{self, Write};
+use std::iter;
+
+/// Everything known about one game, judged against a bag.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct GameRecord<'a> {
+    pub id: i32,
+    /// Each pull's count of every colour it drew.
+    pub pulls: &'a [Pull],
+    pub min_bag: &'a Cubes,
+    /// `None` if the power doesn't fit in a `u64`.
+    pub power: Option<u64>,
+    /// Whether the game could have been played with the bag.
+    pub valid: bool,
+}
+
+impl Game {
+    pub fn record(&self, bag: &Cubes) -> GameRecord<'_> {
+        GameRecord {
+            id: self.id(),
+            pulls: self.pulls(),
+            min_bag: self.min_bag(),
+            power: self.power(bag),
+            valid: self.fits_in(bag),
+        }
+    }
+}
+
+/// Writes the games as a JSON array of [`GameRecord`]s.
+pub fn write_json(games: &[Game], bag: &Cubes, out: impl Write) -> io::Result<()> {
+    let records: Vec<GameRecord> = games.iter().map(|game| game.record(bag)).collect();
+    serde_json::to_writer_pretty(out, &records)?;
+    Ok(())
+}
+
+/// Writes one CSV row per pull, with a column for each colour drawn or in
+/// `bag`, then the game's smallest bag, power and validity. A game without
+/// pulls gets a single row with its pull number and counts left empty, and a
+/// power too big for a `u64` is left empty too.
+pub fn write_csv(games: &[Game], bag: &Cubes, mut out: impl Write) -> io::Result<()> {
+    let colours: BTreeSet<&str> = games
+        .iter()
+        .flat_map(|game| game.min_bag().colours())
+        .chain(bag.colours())
+        .collect();
+
+    let mut header = vec![String::from("game"), String::from("pull")];
+    header.extend(colours.iter().map(|colour| colour.to_string()));
+    header.extend(colours.iter().map(|colour| format!("min_{colour}")));
+    header.extend(["power", "valid"].map(String::from));
+    write_row(&mut out, &header)?;
+
+    for game in games {
+        let record = game.record(bag);
+        let mut totals: Vec<String> = colours
+            .iter()
+            .map(|&colour| record.min_bag.count(colour).to_string())
+            .collect();
+        totals.push(
+            record
+                .power
+                .map_or(String::new(), |power| power.to_string()),
+        );
+        totals.push(record.valid.to_string());
+
+        let pulls: Vec<Option<&Pull>> = match record.pulls {
+            [] => vec![None],
+            pulls => pulls.iter().map(Some).collect(),
+        };
+        for (index, pull) in pulls.into_iter().enumerate() {
+            let mut row = vec![record.id.to_string()];
+            match pull {
+                Some(pull) => {
+                    row.push((index + 1).to_string());
+                    row.extend(colours.iter().map(|&colour| pull.count(colour).to_string()));
+                }
+                None => row.extend(iter::repeat_n(String::new(), colours.len() + 1)),
+            }
+            row.extend(totals.iter().cloned());
+            write_row(&mut out, &row)?;
+        }
+    }
+
+    Ok(())
+}
+
+fn write_row(out: &mut impl Write, fields: &[String]) -> io::Result<()> {
+    let fields: Vec<String> = fields.iter().map(|field| quote(field)).collect();
+    writeln!(out, "{}", fields.join(","))
+}
+
+/// Quotes a CSV field if it needs it, doubling any quotes inside.
+fn quote(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse;
+
+    const GAMES: &str = "Game 1: 3 blue, 4 red; 1 red, 2 green\nGame 2: 20 red";
+
+    #[test]
+    fn test_json() {
+        let games = parse(GAMES).unwrap();
+        let mut out = Vec::new();
+        write_json(&games, &Cubes::puzzle_bag(), &mut out).unwrap();
+
+        let json: serde_json::Value = serde_json::from_slice(&out).unwrap();
+        assert_eq!(
+            serde_json::json!({
+                "id": 1,
+                "pulls": [{"blue": 3, "red": 4}, {"green": 2, "red": 1}],
+                "min_bag": {"blue": 3, "green": 2, "red": 4},
+                "power": 24,
+                "valid": true,
+            }),
+            json[0]
+        );
+        assert_eq!(false, json[1]["valid"]);
+        assert_eq!(0, json[1]["power"]);
+    }
+
+    #[test]
+    fn test_csv() {
+        let mut games = parse(GAMES).unwrap();
+        games.push(Game::new(3, Vec::new()));
+        let mut out = Vec::new();
+        write_csv(&games, &Cubes::new().with("red", 20), &mut out).unwrap();
+
+        assert_eq!(
+            "game,pull,blue,green,red,min_blue,min_green,min_red,power,valid\n\
+             1,1,3,0,4,3,2,4,24,false\n\
+             1,2,0,2,1,3,2,4,24,false\n\
+             2,1,0,0,20,0,0,20,20,true\n\
+             3,,,,,0,0,0,0,true\n",
+            String::from_utf8(out).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_power_overflow() {
+        let games = parse("Game 1: 4294967295 a, 4294967295 b, 4294967295 c").unwrap();
+        let bag = Cubes::new();
+
+        let mut out = Vec::new();
+        write_json(&games, &bag, &mut out).unwrap();
+        let json: serde_json::Value = serde_json::from_slice(&out).unwrap();
+        assert_eq!(serde_json::Value::Null, json[0]["power"]);
+
+        let mut out = Vec::new();
+        write_csv(&games, &bag, &mut out).unwrap();
+        assert_eq!(
+            Some("1,1,4294967295,4294967295,4294967295,4294967295,4294967295,4294967295,,false"),
+            String::from_utf8(out).unwrap().lines().nth(1)
+        );
+    }
+
+    #[test]
+    fn test_quote() {
+        assert_eq!("red", quote("red"));
+        assert_eq!("\"a,b\"", quote("a,b"));
+        assert_eq!("\"say \"\"hi\"\"\"", quote("say \"hi\""));
+    }
+}
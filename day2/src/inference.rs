@@ -0,0 +1,328 @@
+//! Estimating what is in the bag from every draw in a game log.
+//!
+//! Each pull is taken to be a handful drawn without replacement from the same
+//! bag, with the cubes put back between pulls. The chance of a pull is then
+//! multivariate hypergeometric: choosing `x` of the `n` cubes of each colour,
+//! out of every way of choosing the handful's size from the whole bag.
+
+use crate::{Cubes, Game};
+use anyhow::{anyhow, bail, ensure, Result};
+use std::collections::BTreeMap;
+
+/// Refuses to score more candidate bags than this.
+const MAX_CANDIDATES: usize = 10_000_000;
+/// Refuses candidate bags holding more cubes than this, whose log factorials
+/// would take too long to tabulate.
+const MAX_BAG_SIZE: usize = 10_000_000;
+/// How far, in log-likelihood, a bag may beat the reference the running sums
+/// are kept against before they are rescaled; `e^600` still fits an `f64`.
+const RESCALE_MARGIN: f64 = 600.0;
+
+/// A bag that might have produced the log.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Candidate {
+    pub bag: Cubes,
+    /// Natural log of the chance of drawing every pull of the log from this bag.
+    pub log_likelihood: f64,
+    /// Chance that this is the bag, all candidates being equally likely beforehand.
+    pub probability: f64,
+}
+
+/// How many cubes of one colour the bag probably holds.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Interval {
+    pub colour: String,
+    /// The count in the most likely bag.
+    pub estimate: u32,
+    pub low: u32,
+    pub high: u32,
+}
+
+/// The result of [`infer_bag`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct Inference {
+    /// How many candidate bags were scored.
+    pub considered: usize,
+    /// The most likely candidates, most likely first.
+    pub candidates: Vec<Candidate>,
+    /// For each colour, the equal-tailed interval holding the requested share
+    /// of the probability, `low..=high`.
+    pub intervals: Vec<Interval>,
+}
+
+impl Inference {
+    pub fn most_likely(&self) -> &Candidate {
+        &self.candidates[0]
+    }
+}
+
+/// Scores every bag holding between the most cubes ever drawn of each colour
+/// and `max_count` of it, keeping the `top` most likely, and gives each
+/// colour's `confidence` interval.
+///
+/// The bags are equally likely beforehand, so the probabilities and intervals
+/// depend on `max_count`; the likelihoods do not.
+pub fn infer_bag(games: &[Game], max_count: u32, confidence: f64, top: usize) -> Result<Inference> {
+    ensure!(
+        confidence > 0.0 && confidence < 1.0,
+        "confidence must be between 0 and 1, got {confidence}"
+    );
+    ensure!(top > 0, "at least one candidate bag must be kept");
+
+    let mut seen = Cubes::new();
+    for game in games {
+        seen.include(game.min_bag());
+    }
+    let colours: Vec<&str> = seen.colours().collect();
+    if colours.is_empty() {
+        bail!("the log has no draws to learn from");
+    }
+    if let Some((colour, count)) = seen.iter().find(|&(_, count)| count > max_count) {
+        bail!("{count} {colour} cubes were drawn at once, more than the maximum of {max_count}");
+    }
+
+    let ranges: Vec<(u32, u32)> = colours
+        .iter()
+        .map(|&colour| (seen.count(colour), max_count))
+        .collect();
+    let considered: usize = ranges
+        .iter()
+        .map(|&(low, high)| (high - low) as usize + 1)
+        .try_fold(1usize, |total, size| total.checked_mul(size))
+        .filter(|&total| total <= MAX_CANDIDATES)
+        .ok_or_else(|| {
+            anyhow!("too many candidate bags; lower the maximum count of {max_count}")
+        })?;
+
+    // Identical pulls are scored once and counted as often as they were drawn.
+    let mut pulls: BTreeMap<Vec<u32>, u32> = BTreeMap::new();
+    for pull in games.iter().flat_map(|game| game.pulls()) {
+        let counts = colours.iter().map(|&colour| pull.count(colour)).collect();
+        *pulls.entry(counts).or_default() += 1;
+    }
+    let largest_bag = (max_count as usize)
+        .checked_mul(colours.len())
+        .filter(|&size| size <= MAX_BAG_SIZE)
+        .ok_or_else(|| {
+            anyhow!("bags of up to {max_count} cubes per colour are too big to score")
+        })?;
+    let ln_choose = LnChoose::up_to(largest_bag);
+
+    // Only the best `top` bags are kept. Each colour's marginal is summed as
+    // likelihoods relative to `reference`, which is raised and the sums
+    // rescaled whenever a bag beats it by enough to risk overflow.
+    let mut best: Vec<(f64, Vec<u32>)> = Vec::with_capacity(top + 1);
+    let mut marginals: Vec<Vec<f64>> = ranges
+        .iter()
+        .map(|&(low, high)| vec![0.0; (high - low) as usize + 1])
+        .collect();
+    let mut reference = f64::NEG_INFINITY;
+    let mut counts: Vec<u32> = ranges.iter().map(|&(low, _)| low).collect();
+    loop {
+        let bag_size: u32 = counts.iter().sum();
+        let log_likelihood: f64 = pulls
+            .iter()
+            .map(|(drawn, &times)| {
+                let handful: u32 = drawn.iter().sum();
+                let ways: f64 = counts
+                    .iter()
+                    .zip(drawn)
+                    .map(|(&held, &taken)| ln_choose.get(held, taken))
+                    .sum();
+                f64::from(times) * (ways - ln_choose.get(bag_size, handful))
+            })
+            .sum();
+
+        if log_likelihood > reference + RESCALE_MARGIN || reference == f64::NEG_INFINITY {
+            let factor = (reference - log_likelihood).exp();
+            for weight in marginals.iter_mut().flatten() {
+                *weight *= factor;
+            }
+            reference = log_likelihood;
+        }
+        let weight = (log_likelihood - reference).exp();
+        for ((marginal, &count), &(low, _)) in marginals.iter_mut().zip(&counts).zip(&ranges) {
+            marginal[(count - low) as usize] += weight;
+        }
+
+        if best.len() < top || log_likelihood > best[best.len() - 1].0 {
+            let at = best.partition_point(|&(other, _)| other >= log_likelihood);
+            best.insert(at, (log_likelihood, counts.clone()));
+            best.truncate(top);
+        }
+
+        if !next_bag(&mut counts, &ranges) {
+            break;
+        }
+    }
+
+    let scale: f64 = marginals[0].iter().sum();
+    let intervals = colours
+        .iter()
+        .zip(&ranges)
+        .zip(&marginals)
+        .enumerate()
+        .map(|(index, ((&colour, &(low, _)), marginal))| {
+            let marginal: BTreeMap<u32, f64> = (low..)
+                .zip(marginal)
+                .map(|(count, &weight)| (count, weight / scale))
+                .collect();
+            let (low, high) = equal_tails(&marginal, confidence);
+            Interval {
+                colour: colour.to_string(),
+                estimate: best[0].1[index],
+                low,
+                high,
+            }
+        })
+        .collect();
+
+    let candidates = best
+        .into_iter()
+        .map(|(log_likelihood, counts)| Candidate {
+            bag: colours
+                .iter()
+                .zip(counts)
+                .fold(Cubes::new(), |bag, (colour, count)| bag.with(colour, count)),
+            log_likelihood,
+            probability: (log_likelihood - reference).exp() / scale,
+        })
+        .collect();
+
+    Ok(Inference {
+        considered,
+        candidates,
+        intervals,
+    })
+}
+
+/// Steps `counts` to the next bag in `ranges`, like an odometer; false once every bag was seen.
+fn next_bag(counts: &mut [u32], ranges: &[(u32, u32)]) -> bool {
+    for (count, &(low, high)) in counts.iter_mut().zip(ranges) {
+        if *count < high {
+            *count += 1;
+            return true;
+        }
+        *count = low;
+    }
+    false
+}
+
+/// The smallest and largest counts leaving at most `(1 - confidence) / 2` of
+/// the probability below and above.
+fn equal_tails(marginal: &BTreeMap<u32, f64>, confidence: f64) -> (u32, u32) {
+    let tail = (1.0 - confidence) / 2.0;
+    let mut cumulative = 0.0;
+    let mut low = None;
+    let mut high = 0;
+
+    for (&count, &probability) in marginal {
+        cumulative += probability;
+        if low.is_none() && cumulative > tail {
+            low = Some(count);
+        }
+        high = count;
+        if cumulative >= 1.0 - tail {
+            break;
+        }
+    }
+
+    (low.unwrap_or(high), high)
+}
+
+/// Logs of binomial coefficients, from a table of log factorials.
+struct LnChoose {
+    ln_factorials: Vec<f64>,
+}
+
+impl LnChoose {
+    fn up_to(n: usize) -> Self {
+        let mut ln_factorials = Vec::with_capacity(n + 1);
+        let mut sum = 0.0;
+        ln_factorials.push(sum);
+        for k in 1..=n {
+            sum += (k as f64).ln();
+            ln_factorials.push(sum);
+        }
+        LnChoose { ln_factorials }
+    }
+
+    /// `ln(n choose k)`, for `k <= n`.
+    fn get(&self, n: u32, k: u32) -> f64 {
+        let (n, k) = (n as usize, k as usize);
+        self.ln_factorials[n] - self.ln_factorials[k] - self.ln_factorials[n - k]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse;
+
+    #[test]
+    fn test_likelihood() -> Result<()> {
+        let games = parse("Game 1: 1 red, 1 blue")?;
+        let inference = infer_bag(&games, 2, 0.9, 10)?;
+
+        // 1 red and 1 blue out of 2 red and 1 blue: 2 of the 3 possible handfuls.
+        let candidate = inference
+            .candidates
+            .iter()
+            .find(|candidate| candidate.bag == "2 red, 1 blue".parse().unwrap())
+            .unwrap();
+        assert!((candidate.log_likelihood.exp() - 2.0 / 3.0).abs() < 1e-9);
+
+        assert_eq!(4, inference.candidates.len());
+        assert_eq!("1 blue, 1 red", inference.most_likely().bag.to_string());
+        let total: f64 = inference.candidates.iter().map(|c| c.probability).sum();
+        assert!((total - 1.0).abs() < 1e-9);
+        Ok(())
+    }
+
+    #[test]
+    fn test_whole_bag_drawn() -> Result<()> {
+        let games = parse("Game 1: 3 red, 2 blue; 2 blue, 3 red\nGame 2: 3 red, 2 blue")?;
+        let inference = infer_bag(&games, 10, 0.95, 5)?;
+
+        assert_eq!("2 blue, 3 red", inference.most_likely().bag.to_string());
+        assert_eq!(9 * 8, inference.considered);
+        assert_eq!(5, inference.candidates.len());
+        for interval in &inference.intervals {
+            assert!(interval.low <= interval.estimate && interval.estimate <= interval.high);
+            assert!(interval.high <= 10);
+        }
+        assert_eq!(
+            ("blue", 2, 2),
+            (
+                inference.intervals[0].colour.as_str(),
+                inference.intervals[0].estimate,
+                inference.intervals[0].low
+            )
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_equal_tails() {
+        let marginal = BTreeMap::from([(1, 0.01), (2, 0.5), (3, 0.48), (4, 0.01)]);
+
+        assert_eq!((2, 3), equal_tails(&marginal, 0.95));
+        assert_eq!((1, 4), equal_tails(&marginal, 0.99));
+    }
+
+    #[test]
+    fn test_errors() -> Result<()> {
+        let games = parse("Game 1: 5 red")?;
+
+        assert!(infer_bag(&games, 4, 0.95, 10).is_err());
+        assert!(infer_bag(&games, 5, 1.5, 10).is_err());
+        assert!(infer_bag(&games, 5, 0.95, 0).is_err());
+        assert!(infer_bag(&[], 5, 0.95, 10).is_err());
+
+        let games = parse("Game 1: 0 red, 1 blue")?;
+        assert!(infer_bag(&games, u32::MAX, 0.95, 10).is_err());
+        let games = parse("Game 1: 4294967290 red")?;
+        assert!(infer_bag(&games, u32::MAX, 0.95, 10).is_err());
+        Ok(())
+    }
+}
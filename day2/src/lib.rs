@@ -0,0 +1,372 @@
+//! Day 2: Cube Conundrum
+//!
+//! Each game reveals handfuls of coloured cubes drawn from a bag. Part 1 sums
+//! the ids of games possible with 12 red, 13 green and 14 blue cubes, part 2
+//! sums the power of the smallest bag that fits each game. Both take any bag,
+//! with any colours, through [`sum_valid_ids`] and [`sum_powers`].
+
+use anyhow::{ensure, Context, Result};
+use aoc_core::{ParseError, Solution};
+use cubes::{cube_list, separator};
+use nom::character::complete::{self, space0, space1};
+use nom::combinator::{eof, not, opt};
+use nom::multi::separated_list1;
+use nom::{IResult, Parser};
+use nom_supreme::{error::ErrorTree, tag::complete::tag, ParserExt};
+use std::cmp::Ordering;
+use std::collections::BTreeSet;
+
+mod cubes;
+mod export;
+mod inference;
+
+pub use cubes::{Cubes, TooManyCubes};
+pub use export::{write_csv, write_json, GameRecord};
+pub use inference::{infer_bag, Candidate, Inference, Interval};
+
+/// Hooks day 2 into the [`Solution`] runner.
+pub struct Day2;
+
+impl Solution for Day2 {
+    const DAY: u8 = 2;
+    type Input = Vec<Game>;
+
+    fn parse(&self, input: &str) -> Result<Vec<Game>> {
+        Ok(parse(input)?)
+    }
+
+    fn part1(&self, games: &Vec<Game>) -> Result<String> {
+        Ok(sum_valid_ids(games, &Cubes::puzzle_bag()).to_string())
+    }
+
+    fn part2(&self, games: &Vec<Game>) -> Result<String> {
+        Ok(sum_powers(games, &Cubes::puzzle_bag())?.to_string())
+    }
+}
+
+/// Parses one game per line, skipping blank lines.
+pub fn parse(input: &str) -> Result<Vec<Game>, ParseError> {
+    input
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| process_line(line).map_err(|error| error.within(input, line)))
+        .collect()
+}
+
+/// Sums the ids of the games that could have been played with `bag`.
+pub fn sum_valid_ids(games: &[Game], bag: &Cubes) -> i32 {
+    games
+        .iter()
+        .filter(|game| game.fits_in(bag))
+        .map(|game| game.id())
+        .sum()
+}
+
+/// Sums the power of each game's smallest possible bag, over the colours of
+/// `bag` and any others the game draws.
+pub fn sum_powers(games: &[Game], bag: &Cubes) -> Result<u64> {
+    games.iter().try_fold(0u64, |total, game| {
+        game.power(bag)
+            .and_then(|power| total.checked_add(power))
+            .with_context(|| format!("the powers up to game {} overflow a u64", game.id()))
+    })
+}
+
+/// Solves part 1 for a whole puzzle input.
+pub fn part1(input: &str) -> Result<i32> {
+    Ok(sum_valid_ids(&parse(input)?, &Cubes::puzzle_bag()))
+}
+
+/// Solves part 2 for a whole puzzle input.
+pub fn part2(input: &str) -> Result<u64> {
+    sum_powers(&parse(input)?, &Cubes::puzzle_bag())
+}
+
+/// One handful of cubes drawn from the bag.
+pub type Pull = Cubes;
+
+/// One line of the puzzle input, with the smallest bag it needs worked out
+/// once, when it is built. A game is never changed afterwards, only merged into
+/// a new one, so that bag can't fall out of date.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Game {
+    id: i32,
+    pulls: Vec<Pull>,
+    min_bag: Cubes,
+}
+
+impl Game {
+    /// A game of `pulls`, which may be empty.
+    pub fn new(id: i32, pulls: Vec<Pull>) -> Self {
+        let mut min_bag = Cubes::new();
+        for pull in &pulls {
+            min_bag.include(pull);
+        }
+
+        Game { id, pulls, min_bag }
+    }
+
+    pub fn id(&self) -> i32 {
+        self.id
+    }
+
+    pub fn pulls(&self) -> &[Pull] {
+        &self.pulls
+    }
+
+    /// Whether nothing was drawn: such a game fits in any bag, even an empty
+    /// one, and its power is zero.
+    pub fn is_empty(&self) -> bool {
+        self.pulls.is_empty()
+    }
+
+    /// Fewest cubes of each drawn colour the bag can hold for this game.
+    pub fn min_bag(&self) -> &Cubes {
+        &self.min_bag
+    }
+
+    /// Whether every pull fits in `bag`.
+    pub fn fits_in(&self, bag: &Cubes) -> bool {
+        self.min_bag.fits_in(bag)
+    }
+
+    /// The power of the smallest bag, over the colours of `bag` and of this
+    /// game: a colour of `bag` that is never drawn makes it zero. `None` if
+    /// it doesn't fit in a `u64`.
+    pub fn power(&self, bag: &Cubes) -> Option<u64> {
+        if self.is_empty() {
+            return Some(0);
+        }
+        let colours: BTreeSet<&str> = bag.colours().chain(self.min_bag.colours()).collect();
+
+        self.min_bag.power(colours)
+    }
+
+    /// The pulls of both games as one, such as a game logged in two parts.
+    pub fn merge(mut self, other: Game) -> Result<Game> {
+        ensure!(
+            self.id == other.id,
+            "can't merge game {} into game {}",
+            other.id,
+            self.id
+        );
+
+        self.min_bag.include(&other.min_bag);
+        self.pulls.extend(other.pulls);
+        Ok(self)
+    }
+
+    /// Compares the bags the games need: `Less` if every bag that fits `other`
+    /// fits this game too, `None` if each needs more of some colour.
+    pub fn compare_needs(&self, other: &Game) -> Option<Ordering> {
+        match (
+            self.min_bag.fits_in(&other.min_bag),
+            other.min_bag.fits_in(&self.min_bag),
+        ) {
+            (true, true) => Some(Ordering::Equal),
+            (true, false) => Some(Ordering::Less),
+            (false, true) => Some(Ordering::Greater),
+            (false, false) => None,
+        }
+    }
+}
+
+/// Parses a `Game 1: 3 blue, 4 red; 1 red, 2 green` line. Spaces or tabs may
+/// surround the punctuation, and the last pull may end in a `;`.
+pub fn process_line(line: &str) -> Result<Game, ParseError> {
+    let (_, game) = game
+        .all_consuming()
+        .parse(line)
+        .map_err(|error| ParseError::from_nom(line, error))?;
+    Ok(game)
+}
+
+fn game(input: &str) -> IResult<&str, Game, ErrorTree<&str>> {
+    let id = tag("Game")
+        .precedes(complete::i32.context("game id").preceded_by(space1))
+        .terminated(separator(":"))
+        .context("game");
+    // A `;` with nothing after it ends the game rather than starting a pull.
+    let between_pulls = separator(";").terminated(not(space0.and(eof)));
+    let pulls = separated_list1(between_pulls, cube_list).terminated(opt(separator(";")));
+
+    id.and(pulls)
+        .delimited_by(space0)
+        .map(|(id, pulls)| Game::new(id, pulls))
+        .parse(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn test_process_games_object() {
+        let input = fs::read_to_string("test.txt").unwrap();
+
+        let mut lines = input.lines();
+        let first_game = lines.next().expect("should have found a game!");
+        let game = process_line(first_game).unwrap();
+        let pulls: Vec<Pull> = vec![
+            Cubes::new().with("red", 4).with("blue", 3),
+            Cubes::new().with("red", 1).with("blue", 6).with("green", 2),
+            Cubes::new().with("green", 2),
+        ];
+        assert_eq!(game.id(), 1);
+        assert_eq!(game.pulls(), pulls);
+    }
+
+    #[test]
+    fn test_valid_game() {
+        let input = fs::read_to_string("test.txt").unwrap();
+
+        let mut lines = input.lines();
+        let first_game = lines.next().expect("should have found a game!");
+        let game = process_line(first_game).unwrap();
+
+        let result = game.fits_in(&Cubes::puzzle_bag());
+        let answer = true;
+        assert_eq!(result, answer);
+    }
+
+    #[test]
+    fn test_invalid_game() {
+        let input = "Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red";
+        let game = process_line(input).unwrap();
+
+        let result = game.fits_in(&Cubes::puzzle_bag());
+        let answer = false;
+        assert_eq!(result, answer);
+    }
+
+    #[test]
+    fn test_power() {
+        let input = "Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red";
+        let game = process_line(input).unwrap();
+
+        assert_eq!(game.min_bag().count("red"), 20);
+        assert_eq!(game.min_bag().count("blue"), 6);
+        assert_eq!(game.min_bag().count("green"), 13);
+        assert_eq!(game.power(&Cubes::puzzle_bag()), Some(1560));
+    }
+
+    #[test]
+    fn test_other_colours() {
+        let game = process_line("Game 9: 2 red, 3 mauve; 4 red").unwrap();
+        let bag: Cubes = "4 red, 3 mauve".parse().unwrap();
+
+        assert_eq!(
+            Cubes::new().with("red", 4).with("mauve", 3),
+            *game.min_bag()
+        );
+        assert!(!game.fits_in(&Cubes::puzzle_bag()));
+        assert!(game.fits_in(&bag));
+        assert!(!game.fits_in(&"4 red, 2 mauve".parse().unwrap()));
+
+        // No green or blue was drawn, so the smallest bag holds none.
+        assert_eq!(Some(0), game.power(&Cubes::puzzle_bag()));
+        assert_eq!(Some(12), game.power(&bag));
+        assert_eq!(Some(12), game.power(&Cubes::new()));
+    }
+
+    #[test]
+    fn test_empty_game() {
+        let game = Game::new(4, Vec::new());
+
+        assert!(game.is_empty());
+        assert_eq!(Cubes::new(), *game.min_bag());
+        assert!(game.fits_in(&Cubes::new()));
+        assert_eq!(Some(0), game.power(&Cubes::puzzle_bag()));
+        assert_eq!(Some(0), game.power(&Cubes::new()));
+    }
+
+    #[test]
+    fn test_power_overflow() {
+        let game = process_line("Game 5: 4294967295 a, 4294967295 b, 4294967295 c").unwrap();
+        let bag: Cubes = "1 a".parse().unwrap();
+
+        assert_eq!(None, game.power(&bag));
+        let error = sum_powers(&[game], &bag).unwrap_err();
+        assert_eq!("the powers up to game 5 overflow a u64", error.to_string());
+
+        // Each power fits, but not their sum.
+        let game = process_line("Game 6: 4294967295 a, 4294967295 b").unwrap();
+        assert_eq!(Some(18446744065119617025), game.power(&bag));
+        assert!(sum_powers(&[game.clone(), game], &bag).is_err());
+    }
+
+    #[test]
+    fn test_merge_and_compare() {
+        let first = process_line("Game 1: 3 blue, 4 red").unwrap();
+        let second = process_line("Game 1: 1 red, 2 green; 2 blue").unwrap();
+        let merged = first.clone().merge(second.clone()).unwrap();
+
+        assert_eq!(
+            process_line("Game 1: 3 blue, 4 red; 1 red, 2 green; 2 blue").unwrap(),
+            merged
+        );
+        assert_eq!(Some(24), merged.power(&Cubes::puzzle_bag()));
+        assert_eq!(
+            merged,
+            merged.clone().merge(Game::new(1, Vec::new())).unwrap()
+        );
+        assert!(first.clone().merge(Game::new(2, Vec::new())).is_err());
+
+        assert_eq!(None, first.compare_needs(&second));
+        assert_eq!(Some(Ordering::Less), first.compare_needs(&merged));
+        assert_eq!(Some(Ordering::Greater), merged.compare_needs(&second));
+        assert_eq!(
+            Some(Ordering::Equal),
+            Game::new(3, Vec::new()).compare_needs(&Game::new(4, Vec::new()))
+        );
+    }
+
+    #[test]
+    fn test_entire_input() -> Result<()> {
+        let input = fs::read_to_string("test.txt").unwrap();
+
+        assert_eq!(8, part1(&input)?);
+        assert_eq!(2286, part2(&input)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let input = "Game 1: 3 blue, 4 red\nGame 2: 1 blue, x green\nGame 3 1 red";
+        let error = parse(input).unwrap_err();
+        assert_eq!("invalid cube count at line 2, column 17", error.to_string());
+
+        let error = process_line("Game 3 1 red").unwrap_err();
+        assert_eq!("invalid game", error.message);
+
+        let error = process_line("Game 4: 1 red; 2").unwrap_err();
+        assert_eq!("invalid colour at line 1, column 17", error.to_string());
+
+        let error = process_line("Game 5: 1 red, green").unwrap_err();
+        assert_eq!("invalid cube count at line 1, column 16", error.to_string());
+
+        let error = process_line("Game 6: 1 red;; 2 blue").unwrap_err();
+        assert_eq!("invalid cube count at line 1, column 15", error.to_string());
+
+        let error = process_line("Game x: 1 red").unwrap_err();
+        assert_eq!("invalid game id", error.message);
+
+        let error = process_line("Game 8: 4294967295 red, 1 red").unwrap_err();
+        assert_eq!("invalid cube count at line 1, column 25", error.to_string());
+    }
+
+    #[test]
+    fn test_whitespace() {
+        let expected = process_line("Game 7: 3 blue, 4 red; 1 red, 2 green").unwrap();
+
+        for line in [
+            "Game 7: 3 blue, 4 red; 1 red, 2 green;",
+            "  Game  7 :3 blue ,4 red ;  1 red,\t2  green ; ",
+            "Game\t7:\t3 blue,  4 red;1 red, 2 green",
+        ] {
+            assert_eq!(expected, process_line(line).unwrap(), "{line:?}");
+        }
+        assert_eq!(1, parse("\nGame 1: 1 red\n  \n").unwrap().len());
+    }
+}
@@ -0,0 +1,4 @@
+//! Day 3: Gear Ratios
+//!
+//! Not solved yet, so the `aoc` runner has no solution for it; the crate keeps
+//! its place in the workspace until there is one.
@@ -0,0 +1,63 @@
+//! Compares the shared matcher with scanning the winning numbers for each of
+//! mine, on large generated card sets.
+//!
+//! Run with `cargo bench -p day4`.
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use day4::Card;
+
+/// `cards` cards of `winning` and `mine` numbers in `1..max`, from a fixed
+/// pseudo-random sequence so every run sees the same cards.
+fn generate(cards: i32, winning: usize, mine: usize, max: u64) -> Vec<Card> {
+    let mut state: u64 = 0x2023_1204;
+    let mut next = move || {
+        state = state
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        ((state >> 33) % (max - 1) + 1) as i32
+    };
+
+    (1..=cards)
+        .map(|id| Card {
+            id,
+            winning_numbers: (0..winning).map(|_| next()).collect(),
+            my_numbers: (0..mine).map(|_| next()).collect(),
+        })
+        .collect()
+}
+
+/// How both parts matched numbers before the shared matcher.
+fn contains_matches(card: &Card) -> usize {
+    card.my_numbers
+        .iter()
+        .filter(|number| card.winning_numbers.contains(number))
+        .count()
+}
+
+fn bench_matching(c: &mut Criterion) {
+    // Puzzle-like cards fit the bitset, bigger numbers are scanned, and long
+    // lists of them hashed.
+    let sets = [
+        ("puzzle", generate(100_000, 10, 25, 100)),
+        ("wide", generate(100_000, 10, 25, 1_000_000)),
+        ("long", generate(1_000, 1_000, 1_000, 1_000_000)),
+    ];
+
+    for (name, cards) in &sets {
+        let mut group = c.benchmark_group(format!("matching/{name}"));
+        group.sample_size(10);
+        group.bench_function("contains", |b| {
+            b.iter(|| black_box(cards).iter().map(contains_matches).sum::<usize>())
+        });
+        group.bench_function("number_set", |b| {
+            b.iter(|| black_box(cards).iter().map(Card::matches).sum::<usize>())
+        });
+        group.bench_function("total_points", |b| {
+            b.iter(|| day4::total_points(black_box(cards)).unwrap())
+        });
+        group.finish();
+    }
+}
+
+criterion_group!(benches, bench_matching);
+criterion_main!(benches);
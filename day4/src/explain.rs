@@ -0,0 +1,157 @@
+//! Which cards won which copies, for checking the part 2 cascade by hand.
+
+use crate::{copies, Card};
+use anyhow::Result;
+use serde::Serialize;
+use std::io::{self, Write};
+
+/// One card's part in the cascade.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct CardExplanation {
+    pub id: i32,
+    pub matches: usize,
+    /// The cards each copy of this one wins a copy of.
+    pub wins: Vec<i32>,
+    /// Copies in hand in the end, the original included.
+    pub copies: u64,
+    /// Copies in hand of this card and every card before it.
+    pub running_total: u64,
+}
+
+/// Explains every card, in order.
+pub fn explain(cards: &[Card]) -> Result<Vec<CardExplanation>> {
+    let copies = copies(cards)?;
+    let mut running_total: u64 = 0;
+
+    Ok(cards
+        .iter()
+        .zip(copies)
+        .enumerate()
+        .map(|(index, (card, copies))| {
+            // `copies` already checked that the sum fits.
+            running_total += copies;
+            CardExplanation {
+                id: card.id,
+                matches: card.matches(),
+                wins: won_by(cards, index).map(|index| cards[index].id).collect(),
+                copies,
+                running_total,
+            }
+        })
+        .collect())
+}
+
+/// Writes every card in hand as a line, each copy indented under the card that won it.
+///
+/// There is one line per card in the end, so this is only readable for small inputs.
+pub fn write_tree(cards: &[Card], mut out: impl Write) -> io::Result<()> {
+    let mut total: u64 = 0;
+
+    for root in 0..cards.len() {
+        let mut stack = vec![(root, 0)];
+        while let Some((index, depth)) = stack.pop() {
+            let card = &cards[index];
+            let matches = card.matches();
+            let plural = if matches == 1 { "" } else { "es" };
+            writeln!(
+                out,
+                "{:indent$}Card {}: {matches} match{plural}",
+                "",
+                card.id,
+                indent = depth * 2
+            )?;
+            total += 1;
+
+            stack.extend(won_by(cards, index).rev().map(|won| (won, depth + 1)));
+        }
+    }
+
+    writeln!(out, "{total} cards in hand")
+}
+
+/// Writes the explanation as a JSON array of [`CardExplanation`]s.
+pub fn write_json(cards: &[Card], out: impl Write) -> Result<()> {
+    serde_json::to_writer_pretty(out, &explain(cards)?)?;
+    Ok(())
+}
+
+/// Writes one CSV row per card, with the cards it wins separated by spaces.
+pub fn write_csv(cards: &[Card], mut out: impl Write) -> Result<()> {
+    writeln!(out, "card,matches,wins,copies,running_total")?;
+    for card in explain(cards)? {
+        let wins: Vec<String> = card.wins.iter().map(i32::to_string).collect();
+        writeln!(
+            out,
+            "{},{},{},{},{}",
+            card.id,
+            card.matches,
+            wins.join(" "),
+            card.copies,
+            card.running_total
+        )?;
+    }
+    Ok(())
+}
+
+/// Positions of the cards that the card at `index` wins, never past the last.
+fn won_by(cards: &[Card], index: usize) -> impl DoubleEndedIterator<Item = usize> {
+    let last = (index + cards[index].matches()).min(cards.len() - 1);
+    index + 1..=last
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::example;
+
+    #[test]
+    fn test_explain() -> Result<()> {
+        let explained = explain(&example())?;
+
+        assert_eq!(
+            CardExplanation {
+                id: 3,
+                matches: 2,
+                wins: vec![4, 5],
+                copies: 4,
+                running_total: 7,
+            },
+            explained[2]
+        );
+        assert_eq!(Vec::<i32>::new(), explained[5].wins);
+        assert_eq!(30, explained[5].running_total);
+        Ok(())
+    }
+
+    #[test]
+    fn test_tree() -> Result<()> {
+        let mut out = Vec::new();
+        write_tree(&example()[2..], &mut out)?;
+
+        assert_eq!(
+            "Card 3: 2 matches\n  \
+               Card 4: 1 match\n    \
+                 Card 5: 0 matches\n  \
+               Card 5: 0 matches\n\
+             Card 4: 1 match\n  \
+               Card 5: 0 matches\n\
+             Card 5: 0 matches\n\
+             Card 6: 0 matches\n\
+             8 cards in hand\n",
+            String::from_utf8(out)?
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_csv() -> Result<()> {
+        let mut out = Vec::new();
+        write_csv(&example(), &mut out)?;
+        let csv = String::from_utf8(out)?;
+
+        assert_eq!(7, csv.lines().count());
+        assert_eq!(Some("1,4,2 3 4 5,1,1"), csv.lines().nth(1));
+        assert_eq!(Some("6,0,,1,30"), csv.lines().last());
+        Ok(())
+    }
+}
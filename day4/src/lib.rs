@@ -0,0 +1,164 @@
+//! Day 4: Scratchcards
+//!
+//! Every card lists winning numbers and the numbers you have. Part 1 scores
+//! each card by doubling per match, part 2 wins copies of the following cards
+//! and counts how many cards you end up with. Both are presets of a
+//! [`Ruleset`], which can score and copy cards in other ways too.
+
+use anyhow::Result;
+use aoc_core::{ParseError, Solution};
+
+mod explain;
+mod matcher;
+mod part1;
+mod part2;
+mod rules;
+
+pub use explain::{explain, write_csv, write_json, write_tree, CardExplanation};
+pub use matcher::{Matches, NumberSet};
+pub use part2::copies;
+pub use rules::{CopyRule, Outcome, Reach, Ruleset, Scoring};
+
+/// Hooks day 4 into the [`Solution`] runner.
+pub struct Day4;
+
+impl Solution for Day4 {
+    const DAY: u8 = 4;
+    type Input = Vec<Card>;
+
+    fn parse(&self, input: &str) -> Result<Vec<Card>> {
+        Ok(parse(input)?)
+    }
+
+    fn part1(&self, cards: &Vec<Card>) -> Result<String> {
+        Ok(total_points(cards)?.to_string())
+    }
+
+    fn part2(&self, cards: &Vec<Card>) -> Result<String> {
+        Ok(total_cards(cards)?.to_string())
+    }
+}
+
+/// Parses one card per line.
+pub fn parse(input: &str) -> Result<Vec<Card>, ParseError> {
+    input
+        .lines()
+        .map(|line| process_line(line).map_err(|error| error.within(input, line)))
+        .collect()
+}
+
+/// Sums the points of every card.
+pub fn total_points(cards: &[Card]) -> Result<u64> {
+    part1::process(cards)
+}
+
+/// Counts the original cards plus every copy they win.
+pub fn total_cards(cards: &[Card]) -> Result<u64> {
+    part2::process(cards)
+}
+
+/// Solves part 1 for a whole puzzle input.
+pub fn part1(input: &str) -> Result<u64> {
+    total_points(&parse(input)?)
+}
+
+/// Solves part 2 for a whole puzzle input.
+pub fn part2(input: &str) -> Result<u64> {
+    total_cards(&parse(input)?)
+}
+
+/// One scratchcard.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Card {
+    pub id: i32,
+    /// Numbers left of the `|`.
+    pub winning_numbers: Vec<i32>,
+    /// Numbers right of the `|`.
+    pub my_numbers: Vec<i32>,
+}
+
+/// Parses a `Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53` line.
+pub fn process_line(line: &str) -> Result<Card, ParseError> {
+    let (card_name, card_numbers) = line.split_once(": ").ok_or_else(|| {
+        ParseError::at(
+            line,
+            &line[line.len()..],
+            "invalid card",
+            "expected `: ` after the id",
+        )
+    })?;
+    let id = card_name
+        .strip_prefix("Card")
+        .map(str::trim_start)
+        .ok_or_else(|| ParseError::at(line, card_name, "invalid card", "expected `Card <id>`"))?;
+    let card_id = id
+        .parse::<i32>()
+        .map_err(|_| ParseError::at(line, id, "invalid card id", "expected a number"))?;
+    let (winning_numbers_string, my_numbers_string) =
+        card_numbers.split_once(" | ").ok_or_else(|| {
+            ParseError::at(
+                line,
+                card_numbers,
+                "invalid card",
+                "expected ` | ` between the numbers",
+            )
+        })?;
+
+    let winning_numbers = parse_numbers(line, winning_numbers_string)?;
+    let my_numbers = parse_numbers(line, my_numbers_string)?;
+
+    Ok(Card {
+        id: card_id,
+        winning_numbers,
+        my_numbers,
+    })
+}
+
+fn parse_numbers(line: &str, numbers: &str) -> Result<Vec<i32>, ParseError> {
+    numbers
+        .split(' ')
+        .filter(|&num| !num.is_empty())
+        .map(|num| {
+            num.parse::<i32>()
+                .map_err(|_| ParseError::at(line, num, "invalid number", "expected a number"))
+        })
+        .collect()
+}
+
+/// Cards for the tests of every module.
+#[cfg(test)]
+pub(crate) mod fixtures {
+    use super::*;
+    use std::fs;
+
+    /// The puzzle's example cards, from `test.txt`.
+    pub(crate) fn example() -> Vec<Card> {
+        parse(&fs::read_to_string("test.txt").unwrap()).unwrap()
+    }
+
+    /// `count` cards that each win the next `matches` cards.
+    pub(crate) fn winning_cards(count: i32, matches: i32) -> Vec<Card> {
+        (1..=count)
+            .map(|id| Card {
+                id,
+                winning_numbers: (1..=matches).collect(),
+                my_numbers: (1..=matches).collect(),
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_errors() {
+        let input = "Card 1: 41 48 | 83 86\nCard 2: 13 3x | 61 30";
+        let error = parse(input).unwrap_err();
+        assert_eq!("invalid number at line 2, column 12", error.to_string());
+
+        let error = process_line("Card 3: 1 21 53").unwrap_err();
+        assert_eq!("invalid card", error.message);
+    }
+}
@@ -0,0 +1,129 @@
+//! Finding which of my numbers are winning numbers, shared by both parts.
+
+use crate::{Card, Scoring};
+use std::collections::HashSet;
+
+/// Above this many winning numbers that don't fit the bitset, hashing each
+/// of mine beats scanning them all.
+const SCAN_LIMIT: usize = 256;
+
+/// A card's winning numbers, arranged for checking mine against them quickly.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum NumberSet<'a> {
+    /// One bit per number, when they are all below 128 as on real cards.
+    Bits(u128),
+    /// Few enough numbers that checking each in turn is quickest.
+    Few(&'a [i32]),
+    Many(HashSet<i32>),
+}
+
+impl<'a> NumberSet<'a> {
+    pub fn new(numbers: &'a [i32]) -> Self {
+        if numbers.iter().all(|number| (0..128).contains(number)) {
+            NumberSet::Bits(numbers.iter().fold(0, |bits, &number| bits | 1 << number))
+        } else if numbers.len() <= SCAN_LIMIT {
+            NumberSet::Few(numbers)
+        } else {
+            NumberSet::Many(numbers.iter().copied().collect())
+        }
+    }
+
+    pub fn contains(&self, number: i32) -> bool {
+        match self {
+            NumberSet::Bits(bits) => (0..128).contains(&number) && bits & 1 << number != 0,
+            NumberSet::Few(numbers) => numbers.contains(&number),
+            NumberSet::Many(numbers) => numbers.contains(&number),
+        }
+    }
+}
+
+/// My numbers that are winning numbers, in the order they are listed. A
+/// number listed twice matches twice.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Matches {
+    pub numbers: Vec<i32>,
+}
+
+impl Matches {
+    pub fn count(&self) -> usize {
+        self.numbers.len()
+    }
+
+    /// Part 1's points: one for the first match, doubled for each after it.
+    /// `None` if that doesn't fit in a `u64`.
+    pub fn score(&self) -> Option<u64> {
+        Scoring::Doubling.points(self.count())
+    }
+}
+
+impl Card {
+    /// Checks each of my numbers against the winning ones, once.
+    pub fn matched(&self) -> Matches {
+        let winning = NumberSet::new(&self.winning_numbers);
+
+        Matches {
+            numbers: self
+                .my_numbers
+                .iter()
+                .copied()
+                .filter(|&number| winning.contains(number))
+                .collect(),
+        }
+    }
+
+    /// How many of my numbers are winning numbers.
+    pub fn matches(&self) -> usize {
+        let winning = NumberSet::new(&self.winning_numbers);
+
+        self.my_numbers
+            .iter()
+            .filter(|&&number| winning.contains(number))
+            .count()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::process_line;
+
+    #[test]
+    fn test_matched() {
+        let card = process_line("Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53").unwrap();
+        let matches = card.matched();
+
+        assert_eq!(vec![83, 86, 17, 48], matches.numbers);
+        assert_eq!((4, Some(8)), (matches.count(), matches.score()));
+    }
+
+    #[test]
+    fn test_sets() {
+        let bits = NumberSet::new(&[0, 5, 127]);
+        assert!(matches!(bits, NumberSet::Bits(_)));
+        assert!(bits.contains(0) && bits.contains(127));
+        assert!(!bits.contains(6) && !bits.contains(128) && !bits.contains(-1));
+
+        let few = NumberSet::new(&[5, 1000, -3]);
+        assert!(matches!(few, NumberSet::Few(_)));
+        assert!(few.contains(1000) && few.contains(-3));
+        assert!(!few.contains(6));
+
+        let numbers: Vec<i32> = (0..1000).map(|number| number * 7).collect();
+        let many = NumberSet::new(&numbers);
+        assert!(matches!(many, NumberSet::Many(_)));
+        assert!(many.contains(0) && many.contains(6993));
+        assert!(!many.contains(6) && !many.contains(7000));
+    }
+
+    #[test]
+    fn test_score() {
+        let matches = |count: i32| Matches {
+            numbers: (0..count).collect(),
+        };
+
+        assert_eq!(Some(0), matches(0).score());
+        assert_eq!(Some(1), matches(1).score());
+        assert_eq!(Some(1 << 63), matches(64).score());
+        assert_eq!(None, matches(65).score());
+    }
+}
@@ -0,0 +1,20 @@
+use crate::{Card, Ruleset};
+use anyhow::Result;
+
+pub(crate) fn process(cards: &[Card]) -> Result<u64> {
+    Ok(Ruleset::part1().play(cards)?.points)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::process_line;
+
+    #[test]
+    fn test_example_one() {
+        let card = process_line("Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53").unwrap();
+
+        let result = card.matched().score();
+        let answer = Some(8);
+        assert_eq!(result, answer);
+    }
+}
@@ -0,0 +1,58 @@
+use crate::{Card, Ruleset};
+use anyhow::Result;
+
+/// How many of each card end up in hand: the original plus every copy won,
+/// in the order of `cards`, under [`Ruleset::part2`].
+pub fn copies(cards: &[Card]) -> Result<Vec<u64>> {
+    Ok(Ruleset::part2().play(cards)?.copies)
+}
+
+pub(crate) fn process(cards: &[Card]) -> Result<u64> {
+    Ok(Ruleset::part2().play(cards)?.cards)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::{example, winning_cards};
+    use crate::part2;
+    use std::fs;
+
+    #[test]
+    fn test_example_one_day_two() -> Result<()> {
+        let input = fs::read_to_string("test.txt").unwrap();
+
+        let result = part2(&input)?;
+
+        let answer = 30;
+        assert_eq!(result, answer);
+        Ok(())
+    }
+
+    #[test]
+    fn test_copies() -> Result<()> {
+        assert_eq!(vec![1, 2, 4, 8, 14, 1], copies(&example())?);
+        assert_eq!(Vec::<u64>::new(), copies(&[])?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_wins_stop_at_the_last_card() -> Result<()> {
+        assert_eq!(vec![1, 2, 4], copies(&winning_cards(3, 5))?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_large_counts() -> Result<()> {
+        // Every card wins all the ones after it, doubling what each holds:
+        // 2^40 copies of the last.
+        let cards = winning_cards(41, 41);
+        let copies = copies(&cards)?;
+        assert_eq!(1 << 40, copies[40]);
+        assert_eq!((1 << 41) - 1, process(&cards)?);
+
+        assert!(process(&winning_cards(200, 10)).is_err());
+        assert_eq!(100_000, process(&winning_cards(100_000, 0))?);
+        Ok(())
+    }
+}
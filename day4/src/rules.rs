@@ -0,0 +1,326 @@
+//! Rules for what scratchcards are worth, with the two parts as presets.
+
+use crate::Card;
+use anyhow::{bail, Context, Result};
+use std::str::FromStr;
+
+/// How a card's matches turn into points.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Scoring {
+    /// One point for the first match, doubled for each after it.
+    Doubling,
+    /// The same points for every match.
+    Linear { per_match: u64 },
+    /// Points for no match, one match, two and so on; matches past the end of
+    /// the table score its last entry, and an empty table scores nothing.
+    Table(Vec<u64>),
+}
+
+impl Scoring {
+    /// Points for a card with `matches` matches, or `None` if they overflow a `u64`.
+    pub fn points(&self, matches: usize) -> Option<u64> {
+        match self {
+            Scoring::Doubling if matches == 0 => Some(0),
+            Scoring::Doubling => 1u64.checked_shl(u32::try_from(matches - 1).ok()?),
+            Scoring::Linear { per_match } => per_match.checked_mul(u64::try_from(matches).ok()?),
+            Scoring::Table(table) => {
+                Some(table.get(matches).or(table.last()).copied().unwrap_or(0))
+            }
+        }
+    }
+}
+
+impl FromStr for Scoring {
+    type Err = anyhow::Error;
+
+    /// `doubling`, `linear:<points>` or `table:<points>,<points>,...`.
+    fn from_str(scoring: &str) -> Result<Self> {
+        match scoring.split_once(':') {
+            None if scoring == "doubling" => Ok(Scoring::Doubling),
+            Some(("linear", points)) => Ok(Scoring::Linear {
+                per_match: points
+                    .parse()
+                    .with_context(|| format!("invalid points per match `{points}`"))?,
+            }),
+            Some(("table", table)) => Ok(Scoring::Table(
+                table
+                    .split(',')
+                    .map(|points| {
+                        points
+                            .trim()
+                            .parse()
+                            .with_context(|| format!("invalid points `{points}`"))
+                    })
+                    .collect::<Result<_>>()?,
+            )),
+            _ => bail!(
+                "expected `doubling`, `linear:<points>` or `table:<points>,...`, got `{scoring}`"
+            ),
+        }
+    }
+}
+
+/// How many cards after it a winning card wins a copy of.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Reach {
+    /// One card per match.
+    Matches,
+    /// This many cards, if the card matches at all.
+    Fixed(usize),
+}
+
+impl FromStr for Reach {
+    type Err = anyhow::Error;
+
+    /// `matches`, or a number of cards.
+    fn from_str(reach: &str) -> Result<Self> {
+        match reach {
+            "matches" => Ok(Reach::Matches),
+            cards => Ok(Reach::Fixed(cards.parse().with_context(|| {
+                format!("expected `matches` or a number of cards, got `{cards}`")
+            })?)),
+        }
+    }
+}
+
+/// Which copies a card wins for each copy of it held.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CopyRule {
+    pub reach: Reach,
+    /// Whether winnings past the last card carry on from the first. A card
+    /// never wins itself, so it reaches at most every other card once. Copies
+    /// won of a card already played are kept and scored, but win nothing more.
+    pub wrap: bool,
+    /// Most copies of one card that can be held, the original included; any
+    /// more won are lost. At least 1, as the original is always held.
+    pub cap: Option<u64>,
+}
+
+/// How cards are scored and whether they win copies of each other.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Ruleset {
+    pub scoring: Scoring,
+    /// `None` if each card is played once, winning nothing.
+    pub copies: Option<CopyRule>,
+}
+
+/// What a set of cards comes to under a [`Ruleset`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Outcome {
+    /// Copies of each card held in the end, the original included.
+    pub copies: Vec<u64>,
+    /// Every copy held.
+    pub cards: u64,
+    /// The points of every copy held.
+    pub points: u64,
+}
+
+impl Ruleset {
+    /// Part 1: each card once, scored by doubling.
+    pub fn part1() -> Self {
+        Ruleset {
+            scoring: Scoring::Doubling,
+            copies: None,
+        }
+    }
+
+    /// Part 2: each match wins a copy of one of the next cards, never past the last.
+    pub fn part2() -> Self {
+        Ruleset {
+            scoring: Scoring::Linear { per_match: 1 },
+            copies: Some(CopyRule {
+                reach: Reach::Matches,
+                wrap: false,
+                cap: None,
+            }),
+        }
+    }
+
+    /// Plays the cards in order, each copy held winning its share.
+    ///
+    /// The copies being won are carried in running totals, so each card is
+    /// visited once however many it wins.
+    pub fn play(&self, cards: &[Card]) -> Result<Outcome> {
+        if let Some(CopyRule { cap: Some(0), .. }) = self.copies {
+            bail!("the cap on copies must be at least 1, for the original card");
+        }
+        let mut copies = Vec::with_capacity(cards.len());
+        let matches: Vec<usize> = cards.iter().map(Card::matches).collect();
+        // Copies the next card is being won, and when each card's winnings
+        // run out; then the same for winnings wrapped round to the start.
+        let mut winning: u64 = 0;
+        let mut expiring = vec![0u64; cards.len() + 1];
+        let mut wrapped: u64 = 0;
+        let mut wrapped_expiring = vec![0u64; cards.len() + 1];
+
+        for (index, card) in cards.iter().enumerate() {
+            winning -= expiring[index];
+            let won = winning
+                .checked_add(1)
+                .with_context(|| format!("copies of card {} overflow a u64", card.id))?;
+            let held = self.capped(won);
+            copies.push(held);
+
+            let Some(rule) = self.copies else {
+                continue;
+            };
+            let reach = match rule.reach {
+                _ if matches[index] == 0 => 0,
+                Reach::Matches => matches[index],
+                Reach::Fixed(count) => count,
+            };
+            let after = cards.len() - 1 - index;
+            let (forward, round) = if rule.wrap {
+                let reach = reach.min(cards.len() - 1);
+                (reach.min(after), reach.saturating_sub(after))
+            } else {
+                (reach.min(after), 0)
+            };
+
+            let overflow = || format!("copies won by card {} overflow a u64", card.id);
+            if forward > 0 {
+                winning = winning.checked_add(held).with_context(overflow)?;
+                expiring[index + forward + 1] += held;
+            }
+            if round > 0 {
+                wrapped = wrapped.checked_add(held).with_context(overflow)?;
+                wrapped_expiring[round] += held;
+            }
+        }
+
+        for (index, held) in copies.iter_mut().enumerate() {
+            wrapped -= wrapped_expiring[index];
+            let won = held
+                .checked_add(wrapped)
+                .with_context(|| format!("copies of card {} overflow a u64", cards[index].id))?;
+            *held = self.capped(won);
+        }
+
+        let cards_held = copies
+            .iter()
+            .try_fold(0u64, |total, &held| total.checked_add(held))
+            .context("the number of cards overflows a u64")?;
+        let points = copies.iter().zip(&matches).zip(cards).try_fold(
+            0u64,
+            |total, ((&held, &matches), card)| {
+                self.scoring
+                    .points(matches)
+                    .and_then(|points| points.checked_mul(held))
+                    .and_then(|points| total.checked_add(points))
+                    .with_context(|| format!("the points up to card {} overflow a u64", card.id))
+            },
+        )?;
+
+        Ok(Outcome {
+            copies,
+            cards: cards_held,
+            points,
+        })
+    }
+
+    fn capped(&self, copies: u64) -> u64 {
+        match self.copies.and_then(|rule| rule.cap) {
+            Some(cap) => copies.min(cap),
+            None => copies,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::example;
+
+    fn with_copies(reach: Reach, wrap: bool, cap: Option<u64>) -> Ruleset {
+        Ruleset {
+            scoring: Scoring::Linear { per_match: 1 },
+            copies: Some(CopyRule { reach, wrap, cap }),
+        }
+    }
+
+    #[test]
+    fn test_presets() -> Result<()> {
+        let part1 = Ruleset::part1().play(&example())?;
+        assert_eq!((13, 6), (part1.points, part1.cards));
+
+        let part2 = Ruleset::part2().play(&example())?;
+        assert_eq!(vec![1, 2, 4, 8, 14, 1], part2.copies);
+        assert_eq!(30, part2.cards);
+        // Every match of every copy: 4 + 2*2 + 4*2 + 8*1.
+        assert_eq!(24, part2.points);
+        Ok(())
+    }
+
+    #[test]
+    fn test_scoring() -> Result<()> {
+        let table = Scoring::Table(vec![0, 5, 7]);
+        assert_eq!(
+            vec![Some(0), Some(5), Some(7), Some(7)],
+            (0..4)
+                .map(|matches| table.points(matches))
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(Some(0), Scoring::Table(Vec::new()).points(3));
+        assert_eq!(Some(12), Scoring::Linear { per_match: 3 }.points(4));
+        assert_eq!(Some(8), Scoring::Doubling.points(4));
+        assert_eq!(None, Scoring::Doubling.points(65));
+
+        assert_eq!(Scoring::Doubling, "doubling".parse()?);
+        assert_eq!(Scoring::Linear { per_match: 2 }, "linear:2".parse()?);
+        assert_eq!(Scoring::Table(vec![0, 1, 3]), "table:0, 1,3".parse()?);
+        assert!("linear".parse::<Scoring>().is_err());
+        assert!("table:1,x".parse::<Scoring>().is_err());
+
+        let points = Ruleset {
+            scoring: table,
+            copies: None,
+        }
+        .play(&example())?;
+        assert_eq!(7 + 7 + 7 + 5, points.points);
+        Ok(())
+    }
+
+    #[test]
+    fn test_fixed_reach() -> Result<()> {
+        // Cards 1-4 each win the next two; cards 5 and 6 match nothing.
+        let outcome = with_copies(Reach::Fixed(2), false, None).play(&example())?;
+
+        assert_eq!(vec![1, 2, 4, 7, 12, 8], outcome.copies);
+        assert_eq!(Reach::Fixed(3), "3".parse()?);
+        assert_eq!(Reach::Matches, "matches".parse()?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_wrap() -> Result<()> {
+        let mut cards = example();
+        // Card 6 now matches twice, winning cards 1 and 2 for its 1 copy.
+        cards[5].my_numbers = cards[5].winning_numbers[..2].to_vec();
+        let outcome = with_copies(Reach::Matches, true, None).play(&cards)?;
+        assert_eq!(vec![2, 3, 4, 8, 14, 1], outcome.copies);
+
+        // Reaching past every other card still wins each of them only once:
+        // card 2 wraps round to card 1, card 3 to cards 1 and 2.
+        let outcome = with_copies(Reach::Fixed(10), true, None).play(&example()[..3])?;
+        assert_eq!(vec![1 + 2 + 4, 2 + 4, 4], outcome.copies);
+        let outcome = with_copies(Reach::Fixed(10), false, None).play(&example()[..3])?;
+        assert_eq!(vec![1, 2, 4], outcome.copies);
+        Ok(())
+    }
+
+    #[test]
+    fn test_cap() -> Result<()> {
+        let outcome = with_copies(Reach::Matches, false, Some(3)).play(&example())?;
+
+        // Card 3 wins 4 copies but keeps 3, and only those 3 win more.
+        assert_eq!(vec![1, 2, 3, 3, 3, 1], outcome.copies);
+        assert_eq!(13, outcome.cards);
+
+        let outcome = with_copies(Reach::Matches, false, Some(1)).play(&example())?;
+        assert_eq!(vec![1; 6], outcome.copies);
+        assert!(with_copies(Reach::Matches, false, Some(0))
+            .play(&example())
+            .is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,248 @@
+//! Day 5: If You Give A Seed A Fertilizer
+//!
+//! The almanac lists seeds and a chain of maps (seed-to-soil, soil-to-fertilizer,
+//! ...) ending in a location. Part 1 finds the lowest location of the listed
+//! seeds, part 2 reads the seed list as ranges and checks every seed in them.
+
+use anyhow::{bail, Context, Result};
+use aoc_core::{ParseError, Solution};
+use nom::{
+    bytes::complete::take_while1,
+    character::complete::{self, digit1, line_ending, multispace0, multispace1, space1},
+    combinator::{cut, eof, peek},
+    multi::{many1, many_till, separated_list1},
+    sequence::tuple,
+    IResult, Parser,
+};
+use nom_supreme::{error::ErrorTree, tag::complete::tag, ParserExt};
+use rayon::iter::{IntoParallelIterator, ParallelIterator};
+use std::ops::Range;
+use thiserror::Error;
+
+/// Hooks day 5 into the [`Solution`] runner.
+pub struct Day5;
+
+impl Solution for Day5 {
+    const DAY: u8 = 5;
+    type Input = Almanac;
+
+    fn parse(&self, input: &str) -> Result<Almanac> {
+        Ok(parse(input)?)
+    }
+
+    fn part1(&self, almanac: &Almanac) -> Result<String> {
+        Ok(process(almanac)?.to_string())
+    }
+
+    fn part2(&self, almanac: &Almanac) -> Result<String> {
+        Ok(process_ranges(almanac)?.to_string())
+    }
+}
+
+/// The parsed puzzle input.
+#[derive(Debug)]
+pub struct Almanac {
+    /// The numbers after `seeds:`, in input order.
+    pub seeds: Vec<u64>,
+    /// Every map in the order they are applied.
+    pub maps: Vec<SeedMap>,
+}
+
+/// One `x-to-y map:` block.
+#[derive(Debug)]
+pub struct SeedMap {
+    /// `(source, destination)` range pairs of equal length.
+    pub mappings: Vec<(Range<u64>, Range<u64>)>,
+}
+
+impl SeedMap {
+    /// Maps `source` through the first range containing it; unmapped numbers pass through.
+    pub fn translate(&self, source: u64) -> u64 {
+        let valid_mapping = self
+            .mappings
+            .iter()
+            .find(|(source_range, _)| source_range.contains(&source));
+        let Some((source_range, destination_range)) = valid_mapping else {
+            return source;
+        };
+
+        let offset = source - source_range.start;
+
+        destination_range.start + offset
+    }
+}
+
+/// A mapping whose source or destination range runs past the largest `u64`.
+#[derive(Debug, Error)]
+#[error("range ends past {}", u64::MAX)]
+struct RangeOverflow;
+
+fn line(input: &str) -> IResult<&str, (Range<u64>, Range<u64>), ErrorTree<&str>> {
+    tuple((
+        complete::u64,
+        complete::u64.preceded_by(tag(" ")),
+        complete::u64.preceded_by(tag(" ")),
+    ))
+    .map_res(|(destination, source, num)| {
+        let source_end = source.checked_add(num).ok_or(RangeOverflow)?;
+        let destination_end = destination.checked_add(num).ok_or(RangeOverflow)?;
+        Ok::<_, RangeOverflow>((source..source_end, destination..destination_end))
+    })
+    .context("mapping")
+    .parse(input)
+}
+
+/// A `seed-to-soil map:` header followed by one mapping per line.
+///
+/// A line starting with a digit has to be a whole mapping, so a typo in one
+/// is reported there instead of ending the map early.
+fn seed_map(input: &str) -> IResult<&str, SeedMap, ErrorTree<&str>> {
+    take_while1(|c: char| c.is_alphanumeric() || c == '-')
+        .terminated(tag(" map:"))
+        .preceded_by(multispace1)
+        .context("map header")
+        .precedes(many1(
+            line_ending.precedes(peek(digit1)).precedes(cut(line)),
+        ))
+        .map(|mappings| SeedMap { mappings })
+        .parse(input)
+}
+
+#[tracing::instrument(skip_all)]
+fn parse_seedmaps(input: &str) -> IResult<&str, Almanac, ErrorTree<&str>> {
+    let (input, seeds) = tag("seeds: ")
+        .precedes(separated_list1(space1, complete::u64))
+        .context("seeds")
+        .parse(input)?;
+    let (input, (maps, _)) = many_till(seed_map, multispace0.terminated(eof)).parse(input)?;
+
+    Ok((input, Almanac { seeds, maps }))
+}
+
+/// Parses the whole almanac.
+pub fn parse(input: &str) -> Result<Almanac, ParseError> {
+    let (_, almanac) = parse_seedmaps(input).map_err(|error| ParseError::from_nom(input, error))?;
+
+    Ok(almanac)
+}
+
+/// Lowest location of any seed listed in the almanac.
+#[tracing::instrument(skip_all)]
+pub fn process(almanac: &Almanac) -> Result<u64> {
+    let Almanac { seeds, maps } = almanac;
+
+    seeds
+        .iter()
+        .map(|seed| maps.iter().fold(*seed, |seed, map| map.translate(seed)))
+        .min()
+        .context("the almanac lists no seeds")
+}
+
+/// Lowest location when the seed list is read as `start length` pairs.
+///
+/// Checks every seed in every range, so this is slow on real inputs.
+#[tracing::instrument(skip_all)]
+pub fn process_ranges(almanac: &Almanac) -> Result<u64> {
+    let Almanac { seeds, maps } = almanac;
+    if seeds.len() % 2 != 0 {
+        bail!(
+            "{} seed numbers can't be read as `start length` pairs",
+            seeds.len()
+        );
+    }
+    let seeds = seeds
+        .chunks(2)
+        .map(|pair| match pair[0].checked_add(pair[1]) {
+            Some(end) => Ok(pair[0]..end),
+            None => bail!(
+                "the seed range {} {} ends past {}",
+                pair[0],
+                pair[1],
+                u64::MAX
+            ),
+        })
+        .collect::<Result<Vec<Range<u64>>>>()?;
+
+    seeds
+        .into_par_iter()
+        .flat_map(|range| range.clone())
+        .map(|seed| maps.iter().fold(seed, |seed, map| map.translate(seed)))
+        .min()
+        .context("the seed ranges hold no seeds")
+}
+
+/// Solves part 1 for a whole puzzle input.
+pub fn part1(input: &str) -> Result<u64> {
+    process(&parse(input)?)
+}
+
+/// Solves part 2 for a whole puzzle input.
+pub fn part2(input: &str) -> Result<u64> {
+    process_ranges(&parse(input)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test_log::test]
+    fn test_process() -> Result<()> {
+        let input = fs::read_to_string("test.txt").unwrap();
+
+        assert_eq!(35, part1(&input)?);
+        assert_eq!(46, part2(&input)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_translate() {
+        let map = SeedMap {
+            mappings: vec![(98..100, 50..52), (50..98, 52..100)],
+        };
+
+        assert_eq!(81, map.translate(79));
+        assert_eq!(14, map.translate(14));
+        assert_eq!(51, map.translate(99));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let input = fs::read_to_string("test.txt").unwrap();
+
+        let typo = input.replacen("37 52 2", "37 5x 2", 1);
+        let error = parse(&typo).unwrap_err();
+        assert_eq!("invalid mapping at line 9, column 5", error.to_string());
+
+        let header = input.replacen("water-to-light map:", "water-to-light mapp:", 1);
+        let error = parse(&header).unwrap_err();
+        assert_eq!(
+            "invalid map header at line 18, column 15",
+            error.to_string()
+        );
+
+        let seeds = input.replacen("seeds: ", "seeds ", 1);
+        let error = parse(&seeds).unwrap_err();
+        assert_eq!((1, 1), (error.line, error.column));
+
+        let overflow = input.replacen("52 50 48", "1 18446744073709551615 5", 1);
+        let error = parse(&overflow).unwrap_err();
+        assert_eq!("invalid mapping at line 5, column 1", error.to_string());
+    }
+
+    #[test]
+    fn test_seed_range_errors() -> Result<()> {
+        let input = fs::read_to_string("test.txt").unwrap();
+
+        let odd = parse(&input.replacen("seeds: 79 14 55 13", "seeds: 79 14 55", 1))?;
+        assert_eq!(43, process(&odd)?);
+        assert!(process_ranges(&odd).is_err());
+
+        let overflow = parse(&input.replacen("79 14", "79 18446744073709551615", 1))?;
+        assert!(process_ranges(&overflow).is_err());
+
+        let empty = parse(&input.replacen("79 14 55 13", "79 0 55 0", 1))?;
+        assert!(process_ranges(&empty).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,167 @@
+//! Day 6: Wait For It
+//!
+//! Holding the boat's button for `n` milliseconds makes it travel at `n`
+//! millimetres per millisecond for the rest of the race. Part 1 multiplies the
+//! number of winning hold times of every race, part 2 reads the sheet as one
+//! long race.
+
+use anyhow::{Context, Result};
+use aoc_core::{ParseError, Solution};
+use nom::{
+    character::complete::{self, multispace0, space0, space1},
+    multi::separated_list1,
+    IResult, Parser,
+};
+use nom_supreme::{error::ErrorTree, tag::complete::tag, ParserExt};
+use std::iter::zip;
+
+/// Hooks day 6 into the [`Solution`] runner.
+pub struct Day6;
+
+impl Solution for Day6 {
+    const DAY: u8 = 6;
+    type Input = (Vec<u64>, Vec<u64>);
+
+    fn parse(&self, input: &str) -> Result<(Vec<u64>, Vec<u64>)> {
+        Ok(parse(input)?)
+    }
+
+    fn part1(&self, (times, distances): &(Vec<u64>, Vec<u64>)) -> Result<String> {
+        Ok(margin_of_error(times, distances)?.to_string())
+    }
+
+    fn part2(&self, (times, distances): &(Vec<u64>, Vec<u64>)) -> Result<String> {
+        Ok(single_race(times, distances)?.to_string())
+    }
+}
+
+/// Parses the `Time:` and `Distance:` lines into race times and record distances.
+pub fn parse(input: &str) -> Result<(Vec<u64>, Vec<u64>), ParseError> {
+    let mut lines = input.lines();
+    let times_line = lines.next().unwrap_or(input);
+    let distances_line = lines.next().unwrap_or(&input[input.len()..]);
+
+    let (_, times) = parse_times(times_line).map_err(|error| ParseError::from_nom(input, error))?;
+    let (_, distances) =
+        parse_distances(distances_line).map_err(|error| ParseError::from_nom(input, error))?;
+
+    if times.len() != distances.len() {
+        let label = format!("expected {} distances, one per race", times.len());
+        return Err(ParseError::at(
+            input,
+            distances_line,
+            "invalid distances",
+            label,
+        ));
+    }
+
+    Ok((times, distances))
+}
+
+/// Product of the number of ways to beat the record of every race.
+pub fn margin_of_error(times: &[u64], distances: &[u64]) -> Result<u64> {
+    let races: Vec<(u64, u64)> = zip(times.iter().copied(), distances.iter().copied()).collect();
+    let wins: Vec<u64> = races
+        .into_iter()
+        .map(|(time, distance)| process_race(time, distance))
+        .collect::<Result<_>>()?;
+
+    Ok(wins.into_iter().product::<u64>())
+}
+
+/// Number of ways to win when the sheet's numbers are joined into a single race.
+pub fn single_race(times: &[u64], distances: &[u64]) -> Result<u64> {
+    let time = join_digits(times)?;
+    let distance = join_digits(distances)?;
+
+    process_race(time, distance)
+}
+
+/// Solves part 1 for a whole puzzle input.
+pub fn part1(input: &str) -> Result<u64> {
+    let (times, distances) = parse(input)?;
+    margin_of_error(&times, &distances)
+}
+
+/// Solves part 2 for a whole puzzle input.
+pub fn part2(input: &str) -> Result<u64> {
+    let (times, distances) = parse(input)?;
+    single_race(&times, &distances)
+}
+
+fn parse_times(input: &str) -> IResult<&str, Vec<u64>, ErrorTree<&str>> {
+    let (input, values) = tag("Time: ")
+        .precedes(separated_list1(space1, complete::u64).preceded_by(multispace0))
+        .terminated(space0)
+        .all_consuming()
+        .context("race times")
+        .parse(input)?;
+
+    Ok((input, values))
+}
+
+fn parse_distances(input: &str) -> IResult<&str, Vec<u64>, ErrorTree<&str>> {
+    let (input, values) = tag("Distance: ")
+        .precedes(separated_list1(space1, complete::u64).preceded_by(multispace0))
+        .terminated(space0)
+        .all_consuming()
+        .context("record distances")
+        .parse(input)?;
+
+    Ok((input, values))
+}
+
+/// Part 2 ignores the spaces between the numbers, so `7  15   30` is one race of `71530`.
+fn join_digits(values: &[u64]) -> Result<u64> {
+    let digits: String = values.iter().map(|value| value.to_string()).collect();
+    digits
+        .parse::<u64>()
+        .with_context(|| format!("{digits} does not fit in a u64"))
+}
+
+/// Number of hold times that travel further than `distance` in a race lasting `time`.
+pub fn process_race(time: u64, distance: u64) -> Result<u64> {
+    let range = 0..time;
+
+    Ok(range
+        .filter(|seconds| seconds * (time - seconds) > distance)
+        .count() as u64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test_log::test]
+    fn test_process() -> Result<()> {
+        let input = fs::read_to_string("test.txt").unwrap();
+
+        assert_eq!(288, part1(&input)?);
+        assert_eq!(71503, part2(&input)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_process_race() -> Result<()> {
+        assert_eq!(4, process_race(7, 9)?);
+        assert_eq!(8, process_race(15, 40)?);
+        assert_eq!(9, process_race(30, 200)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let error = parse("Time:      7  15   30\nDistance:  9  4O  200").unwrap_err();
+        assert_eq!(
+            "invalid record distances at line 2, column 16",
+            error.to_string()
+        );
+
+        let error = parse("Time:      7  15   30\nDistance:  9  40").unwrap_err();
+        assert_eq!("invalid distances at line 2, column 1", error.to_string());
+
+        let error = parse("Tme:      7  15   30").unwrap_err();
+        assert_eq!((1, 1), (error.line, error.column));
+    }
+}
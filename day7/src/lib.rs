@@ -0,0 +1,213 @@
+//! Day 7: Camel Cards
+//!
+//! Hands of five cards are ranked by type, then card by card, and each hand
+//! wins its bid times its rank. Part 2 turns `J` into the weakest card that
+//! acts as a joker when working out the type.
+
+use anyhow::Result;
+use aoc_core::{ParseError, Solution};
+use nom::{bytes::complete::take_while_m_n, character::complete, sequence::tuple, IResult, Parser};
+use nom_supreme::{error::ErrorTree, tag::complete::tag, ParserExt};
+
+mod part1;
+mod part2;
+
+pub use part1::calculate_type;
+pub use part2::calculate_type as calculate_type_with_jokers;
+
+/// Hooks day 7 into the [`Solution`] runner.
+pub struct Day7;
+
+impl Solution for Day7 {
+    const DAY: u8 = 7;
+    type Input = Vec<(String, i64)>;
+
+    fn parse(&self, input: &str) -> Result<Vec<(String, i64)>> {
+        Ok(parse(input)?)
+    }
+
+    fn part1(&self, hands: &Vec<(String, i64)>) -> Result<String> {
+        let hands = hands
+            .iter()
+            .map(|(cards, bid)| (Hand::new(cards), *bid))
+            .collect();
+
+        Ok(total_winnings(hands).to_string())
+    }
+
+    fn part2(&self, hands: &Vec<(String, i64)>) -> Result<String> {
+        let hands = hands
+            .iter()
+            .map(|(cards, bid)| (Hand::with_jokers(cards), *bid))
+            .collect();
+
+        Ok(total_winnings(hands).to_string())
+    }
+}
+
+/// Parses each line into its cards, e.g. `32T3K`, and its bid.
+pub fn parse(input: &str) -> Result<Vec<(String, i64)>, ParseError> {
+    input
+        .lines()
+        .map(|line| {
+            let (_, (cards, bid)) = parse_line
+                .all_consuming()
+                .parse(line)
+                .map_err(|error| ParseError::from_nom(input, error))?;
+            Ok((cards.to_string(), bid))
+        })
+        .collect()
+}
+
+/// Solves part 1 for a whole puzzle input.
+pub fn part1(input: &str) -> Result<i64> {
+    let hands = parse(input)?
+        .iter()
+        .map(|(cards, bid)| (Hand::new(cards), *bid))
+        .collect();
+
+    Ok(total_winnings(hands))
+}
+
+/// Solves part 2 for a whole puzzle input.
+pub fn part2(input: &str) -> Result<i64> {
+    let hands = parse(input)?
+        .iter()
+        .map(|(cards, bid)| (Hand::with_jokers(cards), *bid))
+        .collect();
+
+    Ok(total_winnings(hands))
+}
+
+/// A single card; higher strength beats lower.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Card {
+    pub strength: u8,
+}
+
+/// Five cards in the order they were dealt, and the type they form.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Hand {
+    pub cards: Vec<Card>,
+    pub hand_type: HandType,
+}
+
+impl Hand {
+    /// Builds a hand with part 1 rules, where `J` is a jack.
+    pub fn new(cards: &str) -> Hand {
+        part1::hand(cards)
+    }
+
+    /// Builds a hand with part 2 rules, where `J` is a weak joker.
+    pub fn with_jokers(cards: &str) -> Hand {
+        part2::hand(cards)
+    }
+
+    /// Whether this hand ranks strictly above `hand`.
+    pub fn is_better_than(&self, hand: &Hand) -> bool {
+        if self.hand_type.value() == hand.hand_type.value() {
+            let mut i = 0;
+            while i < self.cards.len() {
+                let first = self.cards.get(i).unwrap();
+                let second = hand.cards.get(i).unwrap();
+
+                if first.strength == second.strength {
+                    i += 1;
+                } else {
+                    return first.strength > second.strength;
+                }
+            }
+            false
+        } else {
+            self.hand_type.value() > hand.hand_type.value()
+        }
+    }
+}
+
+/// Kind of hand, strongest first.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum HandType {
+    FiveOfAKind,
+    FourOfAKind,
+    FullHouse,
+    ThreeOfAKind,
+    TwoPair,
+    Pair,
+    HighCard,
+}
+
+impl HandType {
+    /// Rank of the type, from 7 for five of a kind down to 1 for high card.
+    pub fn value(&self) -> usize {
+        match *self {
+            HandType::FiveOfAKind => 7,
+            HandType::FourOfAKind => 6,
+            HandType::FullHouse => 5,
+            HandType::ThreeOfAKind => 4,
+            HandType::TwoPair => 3,
+            HandType::Pair => 2,
+            HandType::HighCard => 1,
+        }
+    }
+
+    fn from_matches(card_matches: &[usize]) -> HandType {
+        match card_matches {
+            [5] => HandType::FiveOfAKind,
+            [1, 4] => HandType::FourOfAKind,
+            [2, 3] => HandType::FullHouse,
+            [1, 1, 3] => HandType::ThreeOfAKind,
+            [1, 2, 2] => HandType::TwoPair,
+            [1, 1, 1, 2] => HandType::Pair,
+            _ => HandType::HighCard,
+        }
+    }
+}
+
+fn parse_line(input: &str) -> IResult<&str, (&str, i64), ErrorTree<&str>> {
+    tuple((
+        take_while_m_n(5, 5, |card| "AKQJT98765432".contains(card)).context("hand"),
+        complete::i64.preceded_by(tag(" ")).context("bid"),
+    ))
+    .parse(input)
+}
+
+/// Sums every bid times the rank of its hand, the weakest hand having rank 1.
+pub fn total_winnings(mut hands: Vec<(Hand, i64)>) -> i64 {
+    hands.sort_by(|(a, _), (b, _)| a.is_better_than(b).cmp(&b.is_better_than(a)));
+
+    let mut index = 0;
+    let winnings = std::iter::from_fn(move || {
+        index += 1;
+        if index - 1 < hands.len() {
+            let (_, bid) = hands.get(index - 1).unwrap();
+            Some(index as i64 * bid)
+        } else {
+            None
+        }
+    })
+    .collect::<Vec<_>>();
+
+    winnings.into_iter().sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_jokers() {
+        assert_eq!(HandType::TwoPair, Hand::new("KTJJT").hand_type);
+        assert_eq!(HandType::FourOfAKind, Hand::with_jokers("KTJJT").hand_type);
+        assert!(Hand::new("QQQJA").is_better_than(&Hand::new("T55J5")));
+        assert!(Hand::with_jokers("KTJJT").is_better_than(&Hand::with_jokers("QQQJA")));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let error = parse("32T3K 765\nT55X5 684").unwrap_err();
+        assert_eq!("invalid hand at line 2, column 1", error.to_string());
+
+        let error = parse("32T3K 765\nKK677 2B").unwrap_err();
+        assert_eq!((2, 8), (error.line, error.column));
+    }
+}
@@ -0,0 +1,74 @@
+use crate::{Card, Hand, HandType};
+
+fn card(card: char) -> Card {
+    let strength = match card {
+        'A' => 14,
+        'K' => 13,
+        'Q' => 12,
+        'J' => 11,
+        'T' => 10,
+        '9' => 9,
+        '8' => 8,
+        '7' => 7,
+        '6' => 6,
+        '5' => 5,
+        '4' => 4,
+        '3' => 3,
+        '2' => 2,
+        _ => 0,
+    };
+
+    Card { strength }
+}
+
+/// Type of `cards`.
+pub fn calculate_type(mut cards: Vec<Card>) -> HandType {
+    cards.sort_by_key(|card| card.strength);
+
+    let mut unique_cards = cards.clone();
+    unique_cards.dedup();
+
+    let mut index = 0;
+    let mut card_matches = std::iter::from_fn(move || {
+        index += 1;
+
+        if index - 1 < unique_cards.len() {
+            Some(
+                cards
+                    .iter()
+                    .filter(|&card| card == unique_cards.get(index - 1).unwrap())
+                    .count(),
+            )
+        } else {
+            None
+        }
+    })
+    .collect::<Vec<_>>();
+    card_matches.sort();
+
+    HandType::from_matches(&card_matches)
+}
+
+pub(crate) fn hand(cards: &str) -> Hand {
+    let hand = cards.chars().map(card).collect::<Vec<_>>();
+
+    Hand {
+        cards: hand.clone(),
+        hand_type: calculate_type(hand),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::part1;
+    use anyhow::Result;
+    use std::fs;
+
+    #[test_log::test]
+    fn test_process() -> Result<()> {
+        let input = fs::read_to_string("test.txt").unwrap();
+
+        assert_eq!(6440, part1(&input)?);
+        Ok(())
+    }
+}
@@ -0,0 +1,86 @@
+use crate::{Card, Hand, HandType};
+
+fn card(card: char) -> Card {
+    let strength = match card {
+        'A' => 13,
+        'K' => 12,
+        'Q' => 11,
+        'T' => 10,
+        '9' => 9,
+        '8' => 8,
+        '7' => 7,
+        '6' => 6,
+        '5' => 5,
+        '4' => 4,
+        '3' => 3,
+        '2' => 2,
+        'J' => 1,
+        _ => 0,
+    };
+
+    Card { strength }
+}
+
+/// Type of `cards` when jokers count as whatever card helps most.
+pub fn calculate_type(mut cards: Vec<Card>) -> HandType {
+    cards.sort_by_key(|card| card.strength);
+
+    let num_jokers = cards.iter().filter(|&card| card.strength == 1).count();
+    let mut unique_cards: Vec<Card> = cards
+        .clone()
+        .into_iter()
+        .filter(|card| card.strength != 1)
+        .collect();
+    unique_cards.dedup();
+
+    let mut index = 0;
+    let mut card_matches = std::iter::from_fn(move || {
+        index += 1;
+
+        if index - 1 < unique_cards.len() {
+            Some(
+                cards
+                    .iter()
+                    .filter(|&card| card == unique_cards.get(index - 1).unwrap())
+                    .count(),
+            )
+        } else {
+            None
+        }
+    })
+    .collect::<Vec<_>>();
+    card_matches.sort();
+
+    if card_matches.is_empty() {
+        return HandType::FiveOfAKind;
+    }
+
+    let len = card_matches.len();
+    card_matches[len - 1] += num_jokers;
+
+    HandType::from_matches(&card_matches)
+}
+
+pub(crate) fn hand(cards: &str) -> Hand {
+    let hand = cards.chars().map(card).collect::<Vec<_>>();
+
+    Hand {
+        cards: hand.clone(),
+        hand_type: calculate_type(hand),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::part2;
+    use anyhow::Result;
+    use std::fs;
+
+    #[test_log::test]
+    fn test_process() -> Result<()> {
+        let input = fs::read_to_string("test.txt").unwrap();
+
+        assert_eq!(5905, part2(&input)?);
+        Ok(())
+    }
+}